@@ -1,21 +1,168 @@
-use aoc_2024_public::day4::{part1, part2};
-use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use aoc_2024_public::{
+    inputs, parse, registry,
+    y2024::{
+        day1,
+        day4::{self, part1, part2},
+    },
+};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use std::path::Path;
 
-const INPUT: &str = include_str!("../inputs/day4.txt");
+/// Returns the first input of `day` of 2024 that is `is_valid`, loaded when the benches run rather
+/// than when they build, so they still build without the puzzle inputs, which aren't committed.
+fn input(day: u8, is_valid: fn(&str) -> bool) -> Option<String> {
+    let dir = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/inputs"));
+    inputs::load(dir, 2024, day)
+        .unwrap()
+        .into_iter()
+        .map(|input| input.text)
+        .find(|text| is_valid(text))
+}
 
 fn bench_part1(c: &mut Criterion) {
+    let Some(input) = input(4, day4::is_valid) else {
+        return;
+    };
     c.bench_function("part1", |b| {
-        b.iter(|| part1(black_box(INPUT)))
+        b.iter(|| part1(black_box(&input)))
         // b.iter(|| assert_eq!(part1(black_box(input)), 257))
     });
 }
 
 fn bench_part2(c: &mut Criterion) {
+    let Some(input) = input(4, day4::is_valid) else {
+        return;
+    };
     c.bench_function("part2", |b| {
-        b.iter(|| part2(black_box(INPUT)))
+        b.iter(|| part2(black_box(&input)))
         // b.iter(|| assert_eq!(part2(black_box(input)), 328))
     });
 }
 
-criterion_group!(benches, bench_part1, bench_part2);
+/// Benches the `parse` of every registered day on its own under `Y/dayN/phases/parse`, and the
+/// `solve` of each part on an already parsed input under `Y/dayN/phases/partM/solve`.
+fn bench_phases(c: &mut Criterion) {
+    let dir = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/inputs"));
+    for solutions in registry::SOLUTIONS.chunk_by(|a, b| (a.year, a.day) == (b.year, b.day)) {
+        let (year, day) = (solutions[0].year, solutions[0].day);
+        // The unchecked fast paths can't take inputs of another shape.
+        let Some(input) = inputs::load(dir, year, day)
+            .unwrap()
+            .into_iter()
+            .find(|input| (solutions[0].is_valid)(&input.text))
+        else {
+            continue;
+        };
+        let mut group = c.benchmark_group(format!("{year}/day{day}/phases"));
+        group.bench_function("parse", |b| {
+            b.iter(|| (solutions[0].parse)(black_box(&input.text)))
+        });
+        for solution in solutions {
+            let parsed = (solution.parse)(&input.text);
+            group.bench_function(format!("part{}/solve", solution.part), |b| {
+                b.iter(|| (solution.solve)(black_box(&parsed)))
+            });
+        }
+        group.finish();
+    }
+}
+
+/// Benches every implementation of each registered day and part side by side, in a `Y/dayN/partM`
+/// group, on each input under `<implementation>/<input>` and, if there are several, on all of them in
+/// a row under `<implementation>/all`.
+fn bench_implementations(c: &mut Criterion) {
+    let dir = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/inputs"));
+    for solution in registry::SOLUTIONS {
+        // The unchecked fast paths can't take inputs of another shape.
+        let inputs = inputs::load(dir, solution.year, solution.day)
+            .unwrap()
+            .into_iter()
+            .filter(|input| (solution.is_valid)(&input.text))
+            .collect::<Vec<_>>();
+        if inputs.is_empty() {
+            continue;
+        }
+        let mut group = c.benchmark_group(format!(
+            "{}/day{}/part{}",
+            solution.year, solution.day, solution.part
+        ));
+        for implementation in solution.implementations {
+            for input in &inputs {
+                group.bench_function(BenchmarkId::new(implementation.name, &input.name), |b| {
+                    b.iter(|| (implementation.run)(black_box(&input.text)))
+                });
+            }
+            if inputs.len() > 1 {
+                group.bench_function(BenchmarkId::new(implementation.name, "all"), |b| {
+                    b.iter(|| {
+                        for input in &inputs {
+                            black_box((implementation.run)(black_box(&input.text)));
+                        }
+                    })
+                });
+            }
+        }
+        group.finish();
+    }
+}
+
+/// Parses the 2000 five-digit numbers of the day 1 input with each of the `parse` variants.
+fn bench_parse(c: &mut Criterion) {
+    let Some(input) = input(1, day1::is_valid) else {
+        return;
+    };
+    let mut group = c.benchmark_group("parse");
+    group.bench_function("fixed", |b| {
+        b.iter(|| {
+            let input = black_box(input.as_bytes());
+            (0..1000)
+                .flat_map(|line| [line * 14, line * 14 + 8])
+                .map(|i| unsafe { parse::fixed_unchecked::<5>(&input[i..]).0 })
+                .sum::<u32>()
+        })
+    });
+    group.bench_function("bounded", |b| {
+        b.iter(|| {
+            let input = black_box(input.as_bytes());
+            (0..1000)
+                .flat_map(|line| [line * 14, line * 14 + 8])
+                .map(|i| parse::bounded::<5>(&input[i..]).unwrap().0)
+                .sum::<u32>()
+        })
+    });
+    group.bench_function("many_fixed", |b| {
+        b.iter(|| {
+            let input = black_box(input.as_bytes());
+            (0..125)
+                .flat_map(|lines| [lines * 8 * 14, lines * 8 * 14 + 8])
+                .map(|i| {
+                    parse::many_fixed::<5, 8>(&input[i..], 14)
+                        .0
+                        .iter()
+                        .sum::<u32>()
+                })
+                .sum::<u32>()
+        })
+    });
+    #[cfg(target_arch = "x86_64")]
+    group.bench_function("atoi_see", |b| {
+        b.iter(|| {
+            let input = black_box(input.as_bytes());
+            (0..1000)
+                .flat_map(|line| [line * 14, line * 14 + 8])
+                .map(|i| aoc_2024_public::y2024::day1::atoi_see(&input[i..i + 5]))
+                .sum::<u32>()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_part1,
+    bench_part2,
+    bench_parse,
+    bench_implementations,
+    bench_phases
+);
 criterion_main!(benches);
@@ -0,0 +1,46 @@
+#ifndef AOC_2024_PUBLIC_H
+#define AOC_2024_PUBLIC_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* The input is null, not UTF-8 or doesn't have the expected shape. */
+#define AOC_INVALID_INPUT (-1)
+/* The day or part passed to `aoc_solve` isn't solved. */
+#define AOC_UNSOLVED (-2)
+/* The buffer passed to `aoc_solve` can't hold the answer and its NUL terminator. */
+#define AOC_BUFFER_TOO_SMALL (-3)
+/* The answer is negative or doesn't fit in an `int64_t`. `aoc_solve` still writes
+ * answers that fit in 64 bits. */
+#define AOC_OUT_OF_RANGE (-4)
+
+/*
+ * Each function takes the puzzle input as `len` bytes of UTF-8 and returns the
+ * answer, `AOC_INVALID_INPUT` or `AOC_OUT_OF_RANGE`.
+ */
+int64_t aoc_day1_part1(const uint8_t *input, size_t len);
+int64_t aoc_day1_part2(const uint8_t *input, size_t len);
+int64_t aoc_day2_part1(const uint8_t *input, size_t len);
+int64_t aoc_day2_part2(const uint8_t *input, size_t len);
+int64_t aoc_day3_part1(const uint8_t *input, size_t len);
+int64_t aoc_day3_part2(const uint8_t *input, size_t len);
+int64_t aoc_day4_part1(const uint8_t *input, size_t len);
+int64_t aoc_day4_part2(const uint8_t *input, size_t len);
+
+/*
+ * Writes the answer for `day` and `part` as a NUL-terminated string to the
+ * `out_len` bytes at `out` and returns its length, or one of the negative
+ * `AOC_*` error codes.
+ */
+int64_t aoc_solve(uint32_t day, uint32_t part, const uint8_t *input, size_t len, char *out,
+                  size_t out_len);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* AOC_2024_PUBLIC_H */
@@ -0,0 +1,150 @@
+//! C ABI for the solvers, see `include/aoc_2024_public.h`.
+//!
+//! Inputs are passed as a pointer and length and must be valid UTF-8. Like the
+//! `wasm` bindings, every call [`normalize`]s the input and goes through the
+//! checked `try_part*` entry points.
+
+use aoc_2024_public::{
+    normalize::normalize,
+    y2024::{day1, day2, day3, day4},
+    Answer,
+};
+use core::{ffi::c_char, slice};
+
+/// Returned when the input is null, not UTF-8 or doesn't have the expected shape.
+pub const AOC_INVALID_INPUT: i64 = -1;
+/// Returned by [`aoc_solve`] when the day or part isn't solved.
+pub const AOC_UNSOLVED: i64 = -2;
+/// Returned by [`aoc_solve`] when `out` can't hold the answer and its NUL terminator.
+pub const AOC_BUFFER_TOO_SMALL: i64 = -3;
+/// Returned when the answer is negative or doesn't fit in an `int64_t`. [`aoc_solve`]
+/// still writes answers that fit in 64 bits.
+pub const AOC_OUT_OF_RANGE: i64 = -4;
+
+unsafe fn input<'a>(input: *const u8, len: usize) -> Option<&'a str> {
+    if input.is_null() {
+        return None;
+    }
+    core::str::from_utf8(slice::from_raw_parts(input, len)).ok()
+}
+
+unsafe fn solve(input: *const u8, len: usize, solve: fn(&str) -> Option<Answer>) -> Option<Answer> {
+    self::input(input, len).and_then(|input| solve(&normalize(input)))
+}
+
+fn answer(answer: Option<Answer>) -> i64 {
+    answer.map_or(AOC_INVALID_INPUT, |answer| {
+        answer
+            .to_i64()
+            .filter(|answer| *answer >= 0)
+            .unwrap_or(AOC_OUT_OF_RANGE)
+    })
+}
+
+/// # Safety
+///
+/// `input` must be null or point to `len` readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_day1_part1(input: *const u8, len: usize) -> i64 {
+    answer(self::solve(input, len, day1::try_part1))
+}
+
+/// # Safety
+///
+/// `input` must be null or point to `len` readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_day1_part2(input: *const u8, len: usize) -> i64 {
+    answer(self::solve(input, len, day1::try_part2))
+}
+
+/// # Safety
+///
+/// `input` must be null or point to `len` readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_day2_part1(input: *const u8, len: usize) -> i64 {
+    answer(self::solve(input, len, day2::try_part1))
+}
+
+/// # Safety
+///
+/// `input` must be null or point to `len` readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_day2_part2(input: *const u8, len: usize) -> i64 {
+    answer(self::solve(input, len, day2::try_part2))
+}
+
+/// # Safety
+///
+/// `input` must be null or point to `len` readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_day3_part1(input: *const u8, len: usize) -> i64 {
+    answer(self::solve(input, len, day3::try_part1))
+}
+
+/// # Safety
+///
+/// `input` must be null or point to `len` readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_day3_part2(input: *const u8, len: usize) -> i64 {
+    answer(self::solve(input, len, day3::try_part2))
+}
+
+/// # Safety
+///
+/// `input` must be null or point to `len` readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_day4_part1(input: *const u8, len: usize) -> i64 {
+    answer(self::solve(input, len, day4::try_part1))
+}
+
+/// # Safety
+///
+/// `input` must be null or point to `len` readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_day4_part2(input: *const u8, len: usize) -> i64 {
+    answer(self::solve(input, len, day4::try_part2))
+}
+
+/// Writes the answer for `day` and `part` as a NUL-terminated string to `out`
+/// and returns its length, or one of the negative `AOC_*` error codes.
+///
+/// # Safety
+///
+/// `input` must be null or point to `len` readable bytes, and `out` must point
+/// to `out_len` writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(
+    day: u32,
+    part: u32,
+    input: *const u8,
+    len: usize,
+    out: *mut c_char,
+    out_len: usize,
+) -> i64 {
+    let solve: fn(&str) -> Option<Answer> = match (day, part) {
+        (1, 1) => day1::try_part1,
+        (1, 2) => day1::try_part2,
+        (2, 1) => day2::try_part1,
+        (2, 2) => day2::try_part2,
+        (3, 1) => day3::try_part1,
+        (3, 2) => day3::try_part2,
+        (4, 1) => day4::try_part1,
+        (4, 2) => day4::try_part2,
+        _ => return AOC_UNSOLVED,
+    };
+    let answer = match self::solve(input, len, solve) {
+        None => return AOC_INVALID_INPUT,
+        Some(answer) if answer.is_overflow() => return AOC_OUT_OF_RANGE,
+        Some(answer) => answer,
+    };
+
+    let answer = answer.to_string();
+    if out.is_null() || answer.len() >= out_len {
+        return AOC_BUFFER_TOO_SMALL;
+    }
+
+    let out = slice::from_raw_parts_mut(out.cast::<u8>(), out_len);
+    out[..answer.len()].copy_from_slice(answer.as_bytes());
+    out[answer.len()] = 0;
+    answer.len() as i64
+}
@@ -0,0 +1,32 @@
+//! Compiles `tests/test.c` against the shared library and runs it.
+
+use std::{env, path::PathBuf, process::Command};
+
+#[test]
+fn test_c_program() {
+    let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    // Cargo builds the shared library into `target/<profile>/deps`, next to this test.
+    let lib_dir = env::current_exe().unwrap().parent().unwrap().to_owned();
+    let out_dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR"));
+    let program = out_dir.join("test_c_program");
+
+    let status = Command::new(env::var("CC").unwrap_or_else(|_| "cc".into()))
+        .arg(manifest_dir.join("tests/test.c"))
+        .arg("-I")
+        .arg(manifest_dir.join("include"))
+        .arg("-L")
+        .arg(&lib_dir)
+        .arg("-laoc_2024_public_capi")
+        .arg("-o")
+        .arg(&program)
+        .status()
+        .expect("failed to run the C compiler");
+    assert!(status.success());
+
+    let output = Command::new(&program)
+        .env("LD_LIBRARY_PATH", &lib_dir)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(output.stdout, b"ok\n");
+}
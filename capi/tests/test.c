@@ -0,0 +1,72 @@
+#include <assert.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "aoc_2024_public.h"
+
+#define SOLVE(fn, input) fn((const uint8_t *)(input), strlen(input))
+
+static void test_day1(void) {
+    static char input[1000 * 14 + 1];
+    for (int i = 0; i < 1000; i++) {
+        sprintf(input + i * 14, "%05d   %05d\n", 10000 + i, 10999 - i);
+    }
+
+    assert(SOLVE(aoc_day1_part1, input) == 0);
+    assert(SOLVE(aoc_day1_part2, input) == 10499500);
+}
+
+static void test_day2(void) {
+    const char *input = "7 6 4 2 1\n1 2 7 8 9\n9 7 6 2 1\n1 3 2 4 5\n8 6 4 4 1\n1 3 6 7 9\n";
+
+    assert(SOLVE(aoc_day2_part1, input) == 2);
+    assert(SOLVE(aoc_day2_part2, input) == 4);
+
+    const char *windows = "\xef\xbb\xbf" "7 6 4 2 1\r\n1 2 7 8 9\r\n9 7 6 2 1\r\n1 3 2 4 5\r\n8 6 4 4 1\r\n1 3 6 7 9";
+    assert(SOLVE(aoc_day2_part1, windows) == 2);
+}
+
+static void test_day3(void) {
+    assert(SOLVE(aoc_day3_part1,
+                 "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))") == 161);
+    assert(SOLVE(aoc_day3_part2,
+                 "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))") == 48);
+}
+
+static void test_day4(void) {
+    static char input[140 * 141 + 1];
+    for (int i = 0; i < 140 * 141; i++) {
+        input[i] = i % 141 == 140 ? '\n' : "XMAS"[i % 141 % 4];
+    }
+
+    assert(SOLVE(aoc_day4_part1, input) == 14490);
+    assert(SOLVE(aoc_day4_part2, input) == 4830);
+}
+
+static void test_errors(void) {
+    assert(SOLVE(aoc_day1_part1, "12345   67890\n") == AOC_INVALID_INPUT);
+    assert(aoc_day2_part1(NULL, 0) == AOC_INVALID_INPUT);
+    assert(aoc_day2_part1((const uint8_t *)"\xff\n", 2) == AOC_INVALID_INPUT);
+}
+
+static void test_solve(void) {
+    const char *input = "mul(12,34)\n";
+    char out[8];
+
+    assert(aoc_solve(3, 1, (const uint8_t *)input, strlen(input), out, sizeof(out)) == 3);
+    assert(strcmp(out, "408") == 0);
+    assert(aoc_solve(3, 1, (const uint8_t *)input, strlen(input), out, 3) == AOC_BUFFER_TOO_SMALL);
+    assert(aoc_solve(25, 1, (const uint8_t *)input, strlen(input), out, sizeof(out)) == AOC_UNSOLVED);
+}
+
+int main(void) {
+    test_day1();
+    test_day2();
+    test_day3();
+    test_day4();
+    test_errors();
+    test_solve();
+
+    puts("ok");
+    return 0;
+}
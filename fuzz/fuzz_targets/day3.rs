@@ -0,0 +1,21 @@
+#![no_main]
+
+use aoc_2024_public::y2024::day3;
+use aoc_2024_public_fuzz::day3 as reference;
+use libfuzzer_sys::fuzz_target;
+
+fn check(input: &str) {
+    if let Some(answer) = day3::try_part1(input) {
+        assert_eq!(answer.to_string(), reference::part1(input).to_string());
+    }
+    if let Some(answer) = day3::try_part2(input) {
+        assert_eq!(answer.to_string(), reference::part2(input).to_string());
+    }
+}
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = core::str::from_utf8(data) {
+        check(input);
+    }
+    check(&reference::generate(data));
+});
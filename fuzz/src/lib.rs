@@ -0,0 +1,266 @@
+//! Straightforward reference solutions to compare the optimized solvers against,
+//! and generators that turn arbitrary fuzzer bytes into well-formed inputs.
+
+fn byte(data: &[u8], index: usize) -> u8 {
+    if data.is_empty() {
+        0
+    } else {
+        data[index % data.len()]
+    }
+}
+
+pub mod day1 {
+    use super::byte;
+
+    fn parse(input: &str) -> (Vec<u64>, Vec<u64>) {
+        input
+            .lines()
+            .map(|line| {
+                let (l, r) = line.split_once("   ").unwrap();
+                (l.parse::<u64>().unwrap(), r.parse::<u64>().unwrap())
+            })
+            .unzip()
+    }
+
+    pub fn part1(input: &str) -> u64 {
+        let (mut left, mut right) = parse(input);
+        left.sort_unstable();
+        right.sort_unstable();
+        left.iter().zip(&right).map(|(l, r)| l.abs_diff(*r)).sum()
+    }
+
+    pub fn part2(input: &str) -> u64 {
+        let (left, right) = parse(input);
+        left.iter()
+            .map(|l| l * right.iter().filter(|r| *r == l).count() as u64)
+            .sum()
+    }
+
+    /// Builds 1000 lines of 5-digit number pairs from `data`.
+    pub fn generate(data: &[u8]) -> String {
+        let number = |i: usize| {
+            10_000 + u16::from_le_bytes([byte(data, 2 * i), byte(data, 2 * i + 1)]) as u32
+        };
+
+        (0..1000)
+            .map(|i| format!("{}   {}\n", number(2 * i), number(2 * i + 1)))
+            .collect()
+    }
+}
+
+pub mod day2 {
+    use super::byte;
+
+    fn is_safe(levels: &[u8]) -> bool {
+        let asc = levels.windows(2).all(|w| w[0] < w[1]);
+        let desc = levels.windows(2).all(|w| w[0] > w[1]);
+        let steps = levels
+            .windows(2)
+            .all(|w| (1..=3).contains(&w[0].abs_diff(w[1])));
+        (asc || desc) && steps
+    }
+
+    fn parse(input: &str) -> impl Iterator<Item = Vec<u8>> + '_ {
+        input.lines().map(|line| {
+            line.split(' ')
+                .map(|level| level.parse().unwrap())
+                .collect()
+        })
+    }
+
+    pub fn part1(input: &str) -> u32 {
+        parse(input).filter(|levels| is_safe(levels)).count() as u32
+    }
+
+    pub fn part2(input: &str) -> u32 {
+        parse(input)
+            .filter(|levels| {
+                (0..levels.len()).any(|skip| {
+                    let mut levels = levels.clone();
+                    levels.remove(skip);
+                    is_safe(&levels)
+                })
+            })
+            .count() as u32
+    }
+
+    /// Builds reports of 2 to 8 levels from `data`, with the final newline
+    /// omitted when the last byte is odd.
+    pub fn generate(data: &[u8]) -> String {
+        let mut input = String::new();
+        let mut i = 0;
+        while i < data.len() {
+            let count = byte(data, i) as usize % 7 + 2;
+            let levels = (1..=count)
+                .map(|j| (byte(data, i + j) % 100).to_string())
+                .collect::<Vec<_>>();
+            input.push_str(&levels.join(" "));
+            input.push('\n');
+            i += count + 1;
+        }
+
+        if byte(data, data.len().wrapping_sub(1)) % 2 == 1 {
+            input.pop();
+        }
+        input
+    }
+}
+
+pub mod day3 {
+    fn parse_number(input: &[u8]) -> Option<(usize, u32)> {
+        let len = input
+            .iter()
+            .take(3)
+            .take_while(|b| b.is_ascii_digit())
+            .count();
+        let number = core::str::from_utf8(&input[..len]).ok()?.parse().ok()?;
+        Some((len, number))
+    }
+
+    fn parse_mul(input: &[u8]) -> Option<u64> {
+        let input = input.strip_prefix(b"mul(")?;
+        let (len, first) = parse_number(input)?;
+        let input = input[len..].strip_prefix(b",")?;
+        let (len, second) = parse_number(input)?;
+        input[len..].strip_prefix(b")")?;
+        Some(first as u64 * second as u64)
+    }
+
+    fn solve(input: &str, conditionals: bool) -> u64 {
+        let input = input.as_bytes();
+        let mut enabled = true;
+        let mut result = 0;
+        for i in 0..input.len() {
+            let rest = &input[i..];
+            if rest.starts_with(b"do()") {
+                enabled = true;
+            } else if rest.starts_with(b"don't()") {
+                enabled = !conditionals;
+            } else if let Some(product) = parse_mul(rest).filter(|_| enabled) {
+                result += product;
+            }
+        }
+        result
+    }
+
+    pub fn part1(input: &str) -> u64 {
+        solve(input, false)
+    }
+
+    pub fn part2(input: &str) -> u64 {
+        solve(input, true)
+    }
+
+    /// Builds a memory dump from tokens chosen by `data`, so that instructions
+    /// and near-misses show up much more often than in random bytes.
+    pub fn generate(data: &[u8]) -> String {
+        const TOKENS: &[&str] = &[
+            "mul(", "do()", "don't()", "m", "u", "l", "(", ")", ",", "0", "1", "2", "3", "4", "5",
+            "6", "7", "8", "9", "12", "999", "1000", " ", "\n", "x", "mul",
+        ];
+
+        let mut input = data
+            .iter()
+            .map(|b| TOKENS[*b as usize % TOKENS.len()])
+            .collect::<String>();
+        input.push('\n');
+        input
+    }
+}
+
+pub mod day4 {
+    use super::byte;
+
+    const DIRECTIONS: [(isize, isize); 8] = [
+        (0, 1),
+        (1, 0),
+        (0, -1),
+        (-1, 0),
+        (1, 1),
+        (1, -1),
+        (-1, 1),
+        (-1, -1),
+    ];
+
+    fn parse(input: &str) -> Vec<&[u8]> {
+        input.lines().map(str::as_bytes).collect()
+    }
+
+    fn get(grid: &[&[u8]], y: isize, x: isize) -> Option<u8> {
+        let row = grid.get(usize::try_from(y).ok()?)?;
+        row.get(usize::try_from(x).ok()?).copied()
+    }
+
+    pub fn part1(input: &str) -> usize {
+        let grid = parse(input);
+        let mut count = 0;
+        for y in 0..grid.len() as isize {
+            for x in 0..grid[y as usize].len() as isize {
+                for (dy, dx) in DIRECTIONS {
+                    count += b"XMAS".iter().enumerate().all(|(i, c)| {
+                        get(&grid, y + dy * i as isize, x + dx * i as isize) == Some(*c)
+                    }) as usize;
+                }
+            }
+        }
+        count
+    }
+
+    pub fn part2(input: &str) -> usize {
+        let grid = parse(input);
+        let mut count = 0;
+        for y in 0..grid.len() as isize {
+            for x in 0..grid[y as usize].len() as isize {
+                if get(&grid, y, x) != Some(b'A') {
+                    continue;
+                }
+
+                let is_mas =
+                    |a, b| matches!((a, b), (Some(b'M'), Some(b'S')) | (Some(b'S'), Some(b'M')));
+                count += (is_mas(get(&grid, y - 1, x - 1), get(&grid, y + 1, x + 1))
+                    && is_mas(get(&grid, y - 1, x + 1), get(&grid, y + 1, x - 1)))
+                    as usize;
+            }
+        }
+        count
+    }
+
+    /// Builds a 140x140 grid of `XMAS` letters from `data`.
+    pub fn generate(data: &[u8]) -> String {
+        let mut input = String::with_capacity(141 * 140);
+        for y in 0..140 {
+            for x in 0..140 {
+                input.push(b"XMAS"[byte(data, y * 140 + x) as usize % 4] as char);
+            }
+            input.push('\n');
+        }
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fmt::Display, fs, path::Path};
+
+    /// Checks the answers committed with a synthetic input, which the days also use as examples,
+    /// against the reference solutions.
+    fn check<A: Display, B: Display>(day: u8, part1: fn(&str) -> A, part2: fn(&str) -> B) {
+        let path =
+            Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("../synthetic/2024/day{day}.txt"));
+        let input = fs::read_to_string(&path).unwrap();
+        let answers = fs::read_to_string(path.with_extension("answers")).unwrap();
+        assert_eq!(
+            answers,
+            format!("{}\n{}\n", part1(&input), part2(&input)),
+            "day {day}"
+        );
+    }
+
+    #[test]
+    fn test_synthetic_answers() {
+        check(1, super::day1::part1, super::day1::part2);
+        check(2, super::day2::part1, super::day2::part2);
+        check(3, super::day3::part1, super::day3::part2);
+        check(4, super::day4::part1, super::day4::part2);
+    }
+}
@@ -0,0 +1,259 @@
+//! PyO3 bindings exposing every day as a submodule of `aoc2024`.
+//!
+//! The `partN` functions [`normalize`] the input, go through the checked
+//! `try_part*` entry points and raise `ValueError` for malformed input. The
+//! richer APIs return Python objects built from [`day2::reports`],
+//! [`day3::instructions`] and [`day4::part1_matches`], where only the reports
+//! and matches are normalized, so instruction offsets index the given string,
+//! counted in code points like Python's `str` rather than in UTF-8 bytes.
+//!
+//! [`day2::reports`]: aoc_2024_public::y2024::day2::reports
+//! [`day3::instructions`]: aoc_2024_public::y2024::day3::instructions
+//! [`day4::part1_matches`]: aoc_2024_public::y2024::day4::part1_matches
+
+use aoc_2024_public::{
+    normalize::normalize,
+    y2024::{day2, day3, day4},
+    Answer,
+};
+use pyo3::{
+    exceptions::{PyOverflowError, PyValueError},
+    prelude::*,
+};
+
+/// Formats `value` like Python's `repr` would.
+fn repr(value: Option<impl core::fmt::Debug>) -> String {
+    value.map_or_else(|| "None".into(), |value| format!("{value:?}"))
+}
+
+fn answer<T>(answer: Option<T>, day: u32) -> PyResult<T> {
+    answer.ok_or_else(|| PyValueError::new_err(format!("input is not a valid day {day} input")))
+}
+
+/// Solves the normalized `input` and converts the answer to a Python `int`, raising
+/// `OverflowError` if it overflowed.
+fn solution(solve: fn(&str) -> Option<Answer>, input: &str, day: u32) -> PyResult<i128> {
+    self::answer(solve(&normalize(input)), day)?
+        .to_i128()
+        .ok_or_else(|| PyOverflowError::new_err("the answer does not fit in 64 bits"))
+}
+
+#[pyclass(module = "aoc2024.day2", get_all, frozen)]
+pub struct Report {
+    levels: Vec<u32>,
+    is_safe: bool,
+    removal: Option<usize>,
+}
+
+#[pymethods]
+impl Report {
+    fn is_tolerated(&self) -> bool {
+        self.is_safe || self.removal.is_some()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Report(levels={:?}, is_safe={}, removal={})",
+            self.levels,
+            if self.is_safe { "True" } else { "False" },
+            repr(self.removal)
+        )
+    }
+}
+
+impl From<day2::Report> for Report {
+    fn from(report: day2::Report) -> Self {
+        Self {
+            levels: report.levels.iter().map(|level| *level as u32).collect(),
+            is_safe: report.is_safe,
+            removal: report.removal,
+        }
+    }
+}
+
+#[pyclass(module = "aoc2024.day3", get_all, frozen)]
+pub struct Instruction {
+    /// Index of the instruction in the given string, in code points, so `s[offset:]` starts with it.
+    offset: usize,
+    /// One of `"mul"`, `"do"` or `"don't"`.
+    kind: &'static str,
+    operands: Option<(u16, u16)>,
+}
+
+#[pymethods]
+impl Instruction {
+    fn __repr__(&self) -> String {
+        format!(
+            "Instruction(offset={}, kind='{}', operands={})",
+            self.offset,
+            self.kind,
+            repr(self.operands)
+        )
+    }
+}
+
+impl From<(usize, day3::Instruction)> for Instruction {
+    fn from((offset, instruction): (usize, day3::Instruction)) -> Self {
+        let (kind, operands) = match instruction {
+            day3::Instruction::Mul(l, r) => ("mul", Some((l, r))),
+            day3::Instruction::Do => ("do", None),
+            day3::Instruction::Dont => ("don't", None),
+        };
+        Self {
+            offset,
+            kind,
+            operands,
+        }
+    }
+}
+
+#[pyclass(name = "Match", module = "aoc2024.day4", get_all, frozen)]
+pub struct XmasMatch {
+    row: usize,
+    col: usize,
+    /// Lowercase compass direction, e.g. `"north_east"`.
+    direction: &'static str,
+}
+
+#[pymethods]
+impl XmasMatch {
+    fn __repr__(&self) -> String {
+        format!(
+            "Match(row={}, col={}, direction='{}')",
+            self.row, self.col, self.direction
+        )
+    }
+}
+
+impl From<day4::Match> for XmasMatch {
+    fn from(m: day4::Match) -> Self {
+        let direction = match m.direction {
+            day4::Direction::East => "east",
+            day4::Direction::West => "west",
+            day4::Direction::South => "south",
+            day4::Direction::North => "north",
+            day4::Direction::SouthEast => "south_east",
+            day4::Direction::SouthWest => "south_west",
+            day4::Direction::NorthEast => "north_east",
+            day4::Direction::NorthWest => "north_west",
+        };
+        Self {
+            row: m.row,
+            col: m.col,
+            direction,
+        }
+    }
+}
+
+#[pymodule]
+mod aoc2024 {
+    use pyo3::prelude::*;
+
+    #[pymodule]
+    mod day1 {
+        use pyo3::prelude::*;
+
+        #[pyfunction]
+        fn part1(input: &str) -> PyResult<i128> {
+            crate::solution(aoc_2024_public::y2024::day1::try_part1, input, 1)
+        }
+
+        #[pyfunction]
+        fn part2(input: &str) -> PyResult<i128> {
+            crate::solution(aoc_2024_public::y2024::day1::try_part2, input, 1)
+        }
+    }
+
+    #[pymodule]
+    mod day2 {
+        use pyo3::prelude::*;
+
+        #[pymodule_export]
+        use crate::Report;
+
+        #[pyfunction]
+        fn part1(input: &str) -> PyResult<i128> {
+            crate::solution(aoc_2024_public::y2024::day2::try_part1, input, 2)
+        }
+
+        #[pyfunction]
+        fn part2(input: &str) -> PyResult<i128> {
+            crate::solution(aoc_2024_public::y2024::day2::try_part2, input, 2)
+        }
+
+        #[pyfunction]
+        fn reports(input: &str) -> PyResult<Vec<Report>> {
+            let input = crate::normalize(input);
+            let reports = aoc_2024_public::y2024::day2::is_valid(&input).then(|| {
+                aoc_2024_public::y2024::day2::reports(&input)
+                    .map(Report::from)
+                    .collect()
+            });
+            crate::answer(reports, 2)
+        }
+    }
+
+    #[pymodule]
+    mod day3 {
+        use pyo3::prelude::*;
+
+        #[pymodule_export]
+        use crate::Instruction;
+
+        #[pyfunction]
+        fn part1(input: &str) -> PyResult<i128> {
+            crate::solution(aoc_2024_public::y2024::day3::try_part1, input, 3)
+        }
+
+        #[pyfunction]
+        fn part2(input: &str) -> PyResult<i128> {
+            crate::solution(aoc_2024_public::y2024::day3::try_part2, input, 3)
+        }
+
+        #[pyfunction]
+        fn instructions(input: &str) -> Vec<Instruction> {
+            // The offsets come in order, so counting the code points since the previous one is
+            // enough to turn the byte offsets into Python indices.
+            let (mut byte, mut index) = (0, 0);
+            aoc_2024_public::y2024::day3::instructions(input)
+                .map(|(offset, instruction)| {
+                    index += input[byte..offset].chars().count();
+                    byte = offset;
+                    Instruction::from((index, instruction))
+                })
+                .collect()
+        }
+    }
+
+    #[pymodule]
+    mod day4 {
+        use pyo3::prelude::*;
+
+        #[pymodule_export]
+        use crate::XmasMatch;
+
+        #[pyfunction]
+        fn part1(input: &str) -> PyResult<i128> {
+            crate::solution(aoc_2024_public::y2024::day4::try_part1, input, 4)
+        }
+
+        #[pyfunction]
+        fn part2(input: &str) -> PyResult<i128> {
+            crate::solution(aoc_2024_public::y2024::day4::try_part2, input, 4)
+        }
+
+        /// Returns every `XMAS` as a `Match` of its `X` and reading direction.
+        #[pyfunction]
+        fn part1_matches(input: &str) -> Vec<XmasMatch> {
+            aoc_2024_public::y2024::day4::part1_matches(&crate::normalize(input))
+                .map(XmasMatch::from)
+                .collect()
+        }
+
+        /// Returns the `(row, col)` of the `A` in every `X-MAS`.
+        #[pyfunction]
+        fn part2_matches(input: &str) -> Vec<(usize, usize)> {
+            aoc_2024_public::y2024::day4::part2_matches(&crate::normalize(input)).collect()
+        }
+    }
+}
@@ -0,0 +1,104 @@
+import unittest
+
+from aoc2024 import day1, day2, day3, day4
+
+DAY2_INPUT = """7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9
+"""
+
+DAY4_INPUT = """MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX
+"""
+
+
+class Day1(unittest.TestCase):
+    def test_parts(self):
+        input = "".join(f"{10000 + i:05}   {10999 - i:05}\n" for i in range(1000))
+        self.assertEqual(day1.part1(input), 0)
+        self.assertEqual(day1.part2(input), 10499500)
+
+    def test_invalid_input(self):
+        with self.assertRaises(ValueError):
+            day1.part1("3   4\n")
+
+
+class Day2(unittest.TestCase):
+    def test_parts(self):
+        self.assertEqual(day2.part1(DAY2_INPUT), 2)
+        self.assertEqual(day2.part2(DAY2_INPUT), 4)
+
+    def test_windows_line_endings(self):
+        input = "\ufeff" + DAY2_INPUT.replace("\n", "\r\n") + "\r\n"
+        self.assertEqual(day2.part1(input), 2)
+        self.assertEqual(len(day2.reports(input)), 6)
+
+    def test_reports(self):
+        reports = day2.reports(DAY2_INPUT)
+        self.assertEqual([r.is_safe for r in reports], [True, False, False, False, False, True])
+        self.assertEqual(reports[3].levels, [1, 3, 2, 4, 5])
+        self.assertEqual(reports[3].removal, 1)
+        self.assertTrue(reports[3].is_tolerated())
+        self.assertIsNone(reports[1].removal)
+
+    def test_invalid_input(self):
+        with self.assertRaises(ValueError):
+            day2.reports("1 2 x\n")
+
+
+class Day3(unittest.TestCase):
+    INPUT = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))"
+
+    def test_parts(self):
+        self.assertEqual(day3.part1(self.INPUT), 161)
+        self.assertEqual(day3.part2(self.INPUT), 48)
+
+    def test_instructions(self):
+        instructions = day3.instructions(self.INPUT)
+        self.assertEqual(
+            [(i.offset, i.kind, i.operands) for i in instructions],
+            [
+                (1, "mul", (2, 4)),
+                (20, "don't", None),
+                (28, "mul", (5, 5)),
+                (48, "mul", (11, 8)),
+                (59, "do", None),
+                (64, "mul", (8, 5)),
+            ],
+        )
+
+    def test_instruction_offsets_index_the_string(self):
+        memory = "é→xmul(2,4)€€do()🎄mul(3,5)"
+        instructions = day3.instructions(memory)
+        self.assertEqual([i.offset for i in instructions], [3, 13, 18])
+        for instruction in instructions:
+            self.assertTrue(memory[instruction.offset :].startswith(instruction.kind))
+
+
+class Day4(unittest.TestCase):
+    def test_parts(self):
+        input = ("XMAS" * 35 + "\n") * 140
+        self.assertEqual(day4.part1(input), 14490)
+        self.assertEqual(day4.part2(input), 4830)
+        self.assertEqual(day4.part1(input.replace("\n", "\r\n")), 14490)
+
+    def test_matches(self):
+        matches = day4.part1_matches(DAY4_INPUT)
+        self.assertEqual(len(matches), 18)
+        self.assertIn((0, 5, "east"), [(m.row, m.col, m.direction) for m in matches])
+        self.assertEqual(len(day4.part2_matches(DAY4_INPUT)), 9)
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,224 @@
+//! Extracts the examples of a puzzle and their answers from its page, saved from the browser, so
+//! they don't have to be pasted by hand.
+//!
+//! Each part of the page is an `<article class="day-desc">`. The answer of a part is the last
+//! number in a `<code><em>` of its article, and its example the last `<pre><code>` block before
+//! that answer, or the example of part 1 if part 2 shows none. Parts without an answer, such as
+//! part 2 on a page saved before solving part 1, are skipped.
+
+use aoc_2024_public::{normalize::normalize, Answer};
+use std::{fs, io, path::Path};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    /// The example, [`normalize`]d like the inputs.
+    pub text: String,
+    /// The answers of part 1 and part 2 the page gives for this example.
+    pub answers: [Option<Answer>; 2],
+}
+
+/// Returns the examples of `html`, the one of part 1 first.
+pub fn extract(html: &str) -> Result<Vec<Example>, String> {
+    let articles = blocks(html, "<article class=\"day-desc\">", "</article>");
+    if articles.is_empty() {
+        return Err("the page has no puzzle description".to_owned());
+    }
+
+    let mut examples: Vec<Example> = Vec::new();
+    for (part, (_, article)) in articles.iter().take(2).enumerate() {
+        let Some((at, answer)) = blocks(article, "<code><em>", "</em></code>")
+            .into_iter()
+            .rev()
+            .find_map(|(at, answer)| Some((at, decode(answer).trim().parse::<i128>().ok()?)))
+        else {
+            continue;
+        };
+        let text = blocks(article, "<pre><code>", "</code></pre>")
+            .into_iter()
+            .take_while(|(start, _)| *start < at)
+            .last()
+            .map(|(_, text)| normalize(&decode(&strip_tags(text))).into_owned());
+        let example = match text {
+            Some(text) => match examples.iter_mut().find(|example| example.text == text) {
+                Some(example) => example,
+                None => {
+                    examples.push(Example {
+                        text,
+                        answers: [None; 2],
+                    });
+                    examples.last_mut().unwrap()
+                }
+            },
+            None => examples
+                .first_mut()
+                .ok_or_else(|| format!("part {} has an answer but no example", part + 1))?,
+        };
+        example.answers[part] = Some(Answer::from(answer));
+    }
+    if examples.is_empty() {
+        return Err("the page has no example with an answer".to_owned());
+    }
+    Ok(examples)
+}
+
+/// Writes `examples` to `dir` in the layout of [`inputs`](aoc_2024_public::inputs), as
+/// `YEAR/dayN.txt` and, if part 2 has its own example, `YEAR/dayN/part2.txt`, each with its
+/// `.answers`. Returns the paths written.
+pub fn write(dir: &Path, year: u16, day: u8, examples: &[Example]) -> io::Result<Vec<String>> {
+    let mut written = Vec::new();
+    for (i, example) in examples.iter().enumerate() {
+        let name = match i {
+            0 => format!("{year}/day{day}.txt"),
+            _ => format!("{year}/day{day}/part2.txt"),
+        };
+        let path = dir.join(&name);
+        fs::create_dir_all(path.parent().unwrap())?;
+        fs::write(&path, &example.text)?;
+        let answers = example
+            .answers
+            .map(|answer| answer.map_or("?".to_owned(), |answer| answer.to_string()));
+        fs::write(path.with_extension("answers"), answers.join("\n") + "\n")?;
+        written.push(path.display().to_string());
+    }
+    Ok(written)
+}
+
+/// Returns the offset and contents of every `open ... close` in `text`.
+fn blocks<'a>(text: &'a str, open: &str, close: &str) -> Vec<(usize, &'a str)> {
+    let mut blocks = Vec::new();
+    let mut rest = 0;
+    while let Some(start) = text[rest..]
+        .find(open)
+        .map(|start| rest + start + open.len())
+    {
+        let Some(end) = text[start..].find(close).map(|end| start + end) else {
+            break;
+        };
+        blocks.push((start, &text[start..end]));
+        rest = end + close.len();
+    }
+    blocks
+}
+
+/// Removes the `<em>` and other tags the page highlights parts of an example with.
+fn strip_tags(text: &str) -> String {
+    let mut stripped = String::with_capacity(text.len());
+    let mut in_tag = false;
+    for c in text.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => stripped.push(c),
+            _ => {}
+        }
+    }
+    stripped
+}
+
+fn decode(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example(text: &str, part1: Option<i64>, part2: Option<i64>) -> Example {
+        Example {
+            text: text.to_owned(),
+            answers: [part1.map(Answer::from), part2.map(Answer::from)],
+        }
+    }
+
+    #[test]
+    fn test_shared_example() {
+        let examples = extract(include_str!("../tests/fixtures/2024-day2.html")).unwrap();
+        assert_eq!(
+            examples,
+            [example(
+                "7 6 4 2 1\n1 2 7 8 9\n9 7 6 2 1\n1 3 2 4 5\n8 6 4 4 1\n1 3 6 7 9\n",
+                Some(2),
+                Some(4)
+            )]
+        );
+    }
+
+    #[test]
+    fn test_example_per_part() {
+        let examples = extract(include_str!("../tests/fixtures/2024-day3.html")).unwrap();
+        assert_eq!(
+            examples,
+            [
+                example(
+                    "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))\n",
+                    Some(161),
+                    None
+                ),
+                example(
+                    "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))\n",
+                    None,
+                    Some(48)
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_example_before_answer() {
+        // The small grid before the example and the highlighted copy after its answer are skipped.
+        let examples = extract(include_str!("../tests/fixtures/2024-day4.html")).unwrap();
+        assert_eq!(examples.len(), 2);
+        assert!(examples[0].text.starts_with("MMMSXXMASM\nMSAMXMSMSA\n"));
+        assert_eq!(examples[0].text.lines().count(), 10);
+        assert_eq!(examples[0].answers, [Some(Answer::from(18)), None]);
+        assert!(examples[1].text.starts_with(".M.S......\n..A..MSMS.\n"));
+        assert_eq!(examples[1].answers, [None, Some(Answer::from(9))]);
+    }
+
+    #[test]
+    fn test_unsolved_page() {
+        let html = include_str!("../tests/fixtures/2024-day1.html");
+        assert_eq!(
+            extract(html).unwrap(),
+            [example(
+                "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n",
+                Some(11),
+                Some(31)
+            )]
+        );
+        // Saved before solving part 1, the page ends after it.
+        let unsolved = &html[..html.find("<p>Your puzzle answer").unwrap()];
+        assert_eq!(
+            extract(unsolved).unwrap(),
+            [example(
+                "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n",
+                Some(11),
+                None
+            )]
+        );
+
+        assert!(extract("<html><body>Not found</body></html>").is_err());
+        assert!(extract(&html.replace("<pre><code>", "<p>")).is_err());
+    }
+
+    #[test]
+    fn test_write() {
+        let dir = std::env::temp_dir().join(format!("aoc-extract-{}", std::process::id()));
+        let examples = extract(include_str!("../tests/fixtures/2024-day3.html")).unwrap();
+        write(&dir, 2024, 3, &examples).unwrap();
+
+        let inputs = aoc_2024_public::inputs::load(&dir, 2024, 3).unwrap();
+        assert_eq!(inputs.len(), 2);
+        assert_eq!(inputs[0].name, "2024/day3.txt");
+        assert_eq!(inputs[0].text, examples[0].text);
+        assert_eq!(inputs[0].answers, examples[0].answers);
+        assert_eq!(inputs[1].name, "2024/day3/part2.txt");
+        assert_eq!(inputs[1].answers, examples[1].answers);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
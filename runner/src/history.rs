@@ -0,0 +1,372 @@
+//! Local benchmark history, stored as one JSON object per timing run and line.
+
+use aoc_2024_public::{registry::Solution, Answer};
+use serde::{Deserialize, Serialize};
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{self, BufRead, BufReader, Write},
+    path::Path,
+    process::Command,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// Two-sided critical value of the normal distribution for a 99% confidence level.
+const CRITICAL: f64 = 2.576;
+
+/// Summary statistics of the durations of one timing run.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub median: Duration,
+    pub p95: Duration,
+    pub mean_ns: f64,
+    pub stddev_ns: f64,
+    pub samples: u32,
+}
+
+impl Stats {
+    pub fn new(durations: &mut [Duration]) -> Self {
+        assert!(!durations.is_empty(), "no durations to summarize");
+        durations.sort_unstable();
+
+        let n = durations.len() as f64;
+        let mean_ns = durations.iter().map(|d| d.as_nanos() as f64).sum::<f64>() / n;
+        let variance = durations
+            .iter()
+            .map(|d| (d.as_nanos() as f64 - mean_ns).powi(2))
+            .sum::<f64>()
+            / (n - 1.0).max(1.0);
+
+        Self {
+            median: durations[durations.len() / 2],
+            p95: durations[(durations.len() * 95).div_ceil(100) - 1],
+            mean_ns,
+            stddev_ns: variance.sqrt(),
+            samples: durations.len() as u32,
+        }
+    }
+}
+
+/// One timing run of one implementation of a day and part.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Record {
+    /// Seconds since the Unix epoch.
+    pub timestamp: u64,
+    /// Short hash of `HEAD`, with a `-dirty` suffix if tracked files were modified.
+    pub commit: String,
+    pub cpu: String,
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub implementation: String,
+    /// The input name, such as `2024/day1.txt`, or `all` for every input of the day in a row.
+    pub input: String,
+    /// The answer to the input, or `None` for `all`.
+    pub answer: Option<String>,
+    pub samples: u32,
+    pub median_ns: u64,
+    pub p95_ns: u64,
+    pub mean_ns: f64,
+    pub stddev_ns: f64,
+}
+
+impl Record {
+    pub fn new(
+        commit: &str,
+        cpu: &str,
+        solution: &Solution,
+        implementation: &str,
+        input: &str,
+        answer: Option<Answer>,
+        stats: Stats,
+    ) -> Self {
+        Self {
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |elapsed| elapsed.as_secs()),
+            commit: commit.to_owned(),
+            cpu: cpu.to_owned(),
+            year: solution.year,
+            day: solution.day,
+            part: solution.part,
+            implementation: implementation.to_owned(),
+            input: input.to_owned(),
+            answer: answer.map(|answer| answer.to_string()),
+            samples: stats.samples,
+            median_ns: stats.median.as_nanos() as u64,
+            p95_ns: stats.p95.as_nanos() as u64,
+            mean_ns: stats.mean_ns,
+            stddev_ns: stats.stddev_ns,
+        }
+    }
+
+    fn same_benchmark(&self, other: &Self) -> bool {
+        self.year == other.year
+            && self.day == other.day
+            && self.part == other.part
+            && self.implementation == other.implementation
+            && self.input == other.input
+            && self.cpu == other.cpu
+    }
+}
+
+/// Returns the short hash of `HEAD`, or `unknown` outside of a git checkout.
+pub fn commit() -> String {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_owned())
+    };
+    let Some(hash) = git(&["rev-parse", "--short=12", "HEAD"]) else {
+        return "unknown".to_owned();
+    };
+    match git(&["status", "--porcelain", "--untracked-files=no"]) {
+        Some(changes) if !changes.is_empty() => format!("{hash}-dirty"),
+        _ => hash,
+    }
+}
+
+/// Returns the CPU model from `/proc/cpuinfo`, or the architecture where it is unavailable.
+pub fn cpu() -> String {
+    fs::read_to_string("/proc/cpuinfo")
+        .ok()
+        .and_then(|info| {
+            info.lines()
+                .find_map(|line| line.strip_prefix("model name")?.split_once(':'))
+                .map(|(_, model)| model.trim().to_owned())
+        })
+        .unwrap_or_else(|| std::env::consts::ARCH.to_owned())
+}
+
+pub fn append(path: &Path, records: &[Record]) -> io::Result<()> {
+    if let Some(parent) = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        fs::create_dir_all(parent)?;
+    }
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    let mut lines = String::new();
+    for record in records {
+        lines += &serde_json::to_string(record)?;
+        lines.push('\n');
+    }
+    file.write_all(lines.as_bytes())
+}
+
+pub fn load(path: &Path) -> io::Result<Vec<Record>> {
+    let mut records = Vec::new();
+    for (i, line) in BufReader::new(File::open(path)?).lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let record = serde_json::from_str(&line).map_err(|err| {
+            io::Error::new(io::ErrorKind::InvalidData, format!("line {}: {err}", i + 1))
+        })?;
+        records.push(record);
+    }
+    Ok(records)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Regression,
+    Improvement,
+    Unchanged,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison<'a> {
+    pub baseline: &'a Record,
+    pub candidate: &'a Record,
+    /// Relative change of the median, `0.05` being 5% slower.
+    pub change: f64,
+    pub verdict: Verdict,
+}
+
+/// Returns the commit of the latest record starting with `prefix`, or of the latest record at all.
+pub fn resolve<'a>(records: &'a [Record], prefix: Option<&str>) -> Option<&'a str> {
+    records
+        .iter()
+        .rev()
+        .find(|record| prefix.is_none_or(|prefix| record.commit.starts_with(prefix)))
+        .map(|record| record.commit.as_str())
+}
+
+/// Compares the latest run of every benchmark of `candidate` with the latest run of the same benchmark
+/// on the same CPU of `baseline`.
+///
+/// A change is only a regression or an improvement if Welch's t-test on the means is significant at
+/// the 99% level and the medians differ by more than `threshold`, which filters out differences that
+/// are real but too small to matter.
+pub fn compare<'a>(
+    records: &'a [Record],
+    baseline: &str,
+    candidate: &str,
+    threshold: f64,
+) -> Vec<Comparison<'a>> {
+    let latest = |commit: &str, like: &Record| {
+        records
+            .iter()
+            .rev()
+            .find(|record| record.commit == commit && record.same_benchmark(like))
+    };
+
+    let mut comparisons = Vec::<Comparison>::new();
+    for record in records
+        .iter()
+        .rev()
+        .filter(|record| record.commit == candidate)
+    {
+        if comparisons
+            .iter()
+            .any(|comparison| comparison.candidate.same_benchmark(record))
+        {
+            continue;
+        }
+        let Some(base) = latest(baseline, record) else {
+            continue;
+        };
+
+        let change = record.median_ns as f64 / base.median_ns.max(1) as f64 - 1.0;
+        let error = (record.stddev_ns.powi(2) / record.samples.max(1) as f64
+            + base.stddev_ns.powi(2) / base.samples.max(1) as f64)
+            .sqrt();
+        let t = (record.mean_ns - base.mean_ns) / error.max(f64::MIN_POSITIVE);
+        let verdict = if t > CRITICAL && change > threshold {
+            Verdict::Regression
+        } else if t < -CRITICAL && change < -threshold {
+            Verdict::Improvement
+        } else {
+            Verdict::Unchanged
+        };
+
+        comparisons.push(Comparison {
+            baseline: base,
+            candidate: record,
+            change,
+            verdict,
+        });
+    }
+    comparisons.sort_by_key(|c| {
+        (
+            c.candidate.year,
+            c.candidate.day,
+            c.candidate.part,
+            c.candidate.implementation.clone(),
+            c.candidate.input.clone(),
+        )
+    });
+    comparisons
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(commit: &str, day: u8, median_ns: u64, stddev_ns: f64) -> Record {
+        Record {
+            timestamp: 0,
+            commit: commit.to_owned(),
+            cpu: "test".to_owned(),
+            year: 2024,
+            day,
+            part: 1,
+            implementation: "default".to_owned(),
+            input: "2024/day1.txt".to_owned(),
+            answer: Some("11".to_owned()),
+            samples: 100,
+            median_ns,
+            p95_ns: median_ns * 2,
+            mean_ns: median_ns as f64,
+            stddev_ns,
+        }
+    }
+
+    #[test]
+    fn test_stats() {
+        let mut durations = (1..=100)
+            .rev()
+            .map(Duration::from_nanos)
+            .collect::<Vec<_>>();
+        let stats = Stats::new(&mut durations);
+        assert_eq!(stats.median, Duration::from_nanos(51));
+        assert_eq!(stats.p95, Duration::from_nanos(95));
+        assert_eq!(stats.mean_ns, 50.5);
+        assert_eq!(stats.samples, 100);
+
+        let stats = Stats::new(&mut [Duration::from_nanos(7)]);
+        assert_eq!(
+            (stats.median, stats.p95, stats.stddev_ns),
+            (Duration::from_nanos(7), Duration::from_nanos(7), 0.0)
+        );
+    }
+
+    #[test]
+    fn test_compare() {
+        let records = [
+            record("base", 1, 1000, 10.0),
+            record("base", 2, 1000, 10.0),
+            record("base", 3, 1000, 500.0),
+            record("base", 4, 1000, 10.0),
+            record("next", 1, 1100, 10.0),
+            record("next", 2, 900, 10.0),
+            record("next", 3, 1100, 500.0),
+            record("next", 4, 1010, 10.0),
+        ];
+        let verdicts = compare(&records, "base", "next", 0.02)
+            .iter()
+            .map(|comparison| (comparison.candidate.day, comparison.verdict))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            verdicts,
+            [
+                (1, Verdict::Regression),
+                (2, Verdict::Improvement),
+                // Too noisy to be significant.
+                (3, Verdict::Unchanged),
+                // Significant, but below the threshold.
+                (4, Verdict::Unchanged),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_compare_uses_latest_run_on_the_same_cpu() {
+        let mut other = record("base", 1, 2000, 10.0);
+        other.cpu = "other".to_owned();
+        let records = [
+            record("base", 1, 500, 10.0),
+            record("base", 1, 1000, 10.0),
+            other,
+            record("next", 1, 2000, 10.0),
+            record("next", 1, 1000, 10.0),
+        ];
+        let comparisons = compare(&records, "base", "next", 0.02);
+        assert_eq!(comparisons.len(), 1);
+        assert_eq!(comparisons[0].baseline.median_ns, 1000);
+        assert_eq!(comparisons[0].candidate.median_ns, 1000);
+        assert_eq!(comparisons[0].verdict, Verdict::Unchanged);
+    }
+
+    #[test]
+    fn test_append_and_load() {
+        let path = std::env::temp_dir()
+            .join(format!("aoc-history-{}", std::process::id()))
+            .join("history.jsonl");
+        let _ = fs::remove_file(&path);
+
+        let records = [record("base", 1, 1000, 10.0), record("next", 1, 1100, 10.0)];
+        append(&path, &records[..1]).unwrap();
+        append(&path, &records[1..]).unwrap();
+        assert_eq!(load(&path).unwrap(), records);
+        assert_eq!(resolve(&records, None), Some("next"));
+        assert_eq!(resolve(&records, Some("ba")), Some("base"));
+        assert_eq!(resolve(&records, Some("x")), None);
+
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
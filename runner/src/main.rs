@@ -0,0 +1,880 @@
+//! Runs the registered solutions against the inputs in `inputs/` and reports their timings.
+
+mod extract;
+mod history;
+mod memory;
+mod render;
+mod scaffold;
+mod synthesize;
+mod watch;
+
+use aoc_2024_public::{
+    inputs::{self, Input},
+    registry::{self, Solution},
+    trace,
+    y2024::day4,
+    Answer,
+};
+use clap::{Parser, Subcommand, ValueEnum};
+use history::{Record, Stats, Verdict};
+use render::Format;
+use std::{
+    fs,
+    hint::black_box,
+    io::{BufWriter, Write},
+    panic,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+#[global_allocator]
+static ALLOCATOR: memory::Counting = memory::Counting;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Run the Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve the puzzles, check that every implementation agrees and print their median times.
+    Run {
+        /// Only run the days of this year.
+        #[arg(long)]
+        year: Option<u16>,
+        /// Only run this day.
+        #[arg(long)]
+        day: Option<u8>,
+        /// Only run this part.
+        #[arg(long)]
+        part: Option<u8>,
+        /// Only time this implementation, such as `scalar` or `simd`.
+        #[arg(long)]
+        implementation: Option<String>,
+        /// Number of timed runs per solution.
+        #[arg(long, default_value_t = 100)]
+        iterations: u32,
+        /// Also time the parse and solve phases separately.
+        #[arg(long)]
+        phases: bool,
+        /// Also report the heap allocations and the peak heap and stack of every implementation.
+        #[arg(long)]
+        memory: bool,
+        /// Directory holding `YEAR/dayN.txt` and `YEAR/dayN/*.txt`.
+        #[arg(long, default_value = "inputs")]
+        inputs: PathBuf,
+        /// JSON-lines file the timings are appended to.
+        #[arg(long, default_value = "bench-history.jsonl")]
+        history: PathBuf,
+        /// Do not append the timings to the history.
+        #[arg(long)]
+        no_record: bool,
+        /// Also draw the 2024 day 4 matches of every input.
+        #[arg(long, value_enum)]
+        render: Option<Format>,
+        /// Directory the renders are written to.
+        #[arg(long, default_value = "renders")]
+        render_dir: PathBuf,
+        /// Also write the steps behind every answer.
+        #[arg(long, value_enum)]
+        trace: Option<TraceFormat>,
+        /// Directory the traces are written to.
+        #[arg(long, default_value = "traces")]
+        trace_dir: PathBuf,
+    },
+    /// Check every implementation against every input and its known answers, without timing them.
+    Verify {
+        /// Only check the days of this year.
+        #[arg(long)]
+        year: Option<u16>,
+        /// Only check this day.
+        #[arg(long)]
+        day: Option<u8>,
+        /// Only check this part.
+        #[arg(long)]
+        part: Option<u8>,
+        /// Directory holding `YEAR/dayN.txt` and `YEAR/dayN/*.txt`.
+        #[arg(long, default_value = "inputs")]
+        inputs: PathBuf,
+        /// Directory holding the examples in the same layout, as written by `extract`. Only the
+        /// examples the implementations accept are checked.
+        #[arg(long, default_value = "examples")]
+        examples: PathBuf,
+    },
+    /// Extract the examples and their answers from a puzzle page saved from the browser.
+    Extract {
+        /// The year of the puzzle.
+        #[arg(long)]
+        year: u16,
+        /// The day of the puzzle.
+        #[arg(long)]
+        day: u8,
+        /// The saved HTML page.
+        page: PathBuf,
+        /// Directory the examples are written to, as `YEAR/dayN.txt` with their `.answers`.
+        #[arg(long, default_value = "examples")]
+        examples: PathBuf,
+    },
+    /// Generate a synthetic input shaped like a private one, with its answers, to commit instead.
+    Synthesize {
+        /// The year of the input.
+        #[arg(long)]
+        year: u16,
+        /// The day of the input.
+        #[arg(long)]
+        day: u8,
+        /// Directory holding the private `YEAR/dayN.txt`.
+        #[arg(long, default_value = "inputs")]
+        inputs: PathBuf,
+        /// Seed of the random choices, so the same input and seed give the same synthetic input.
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Directory the synthetic input is written to, as `YEAR/dayN.txt` with its `.answers`.
+        #[arg(long, default_value = "synthetic")]
+        out: PathBuf,
+    },
+    /// Rebuild, test and time a day whenever `src/` or the inputs change, and show how its answers
+    /// and timings moved.
+    Watch {
+        /// Only watch the day of this year.
+        #[arg(long)]
+        year: Option<u16>,
+        /// The day to test and time.
+        #[arg(long)]
+        day: u8,
+        /// Only time this part.
+        #[arg(long)]
+        part: Option<u8>,
+        /// Number of timed runs per solution, fewer than `run` for a quick pass.
+        #[arg(long, default_value_t = 20)]
+        iterations: u32,
+        /// Directory holding `YEAR/dayN.txt` and `YEAR/dayN/*.txt`, relative to the root.
+        #[arg(long, default_value = "inputs")]
+        inputs: PathBuf,
+        /// Root of the repository.
+        #[arg(long, default_value = ".")]
+        root: PathBuf,
+        /// How often to look for changes, in milliseconds.
+        #[arg(long, default_value_t = 500)]
+        interval: u64,
+    },
+    /// Compare the recorded timings of two commits and fail on significant regressions.
+    Compare {
+        /// Commit (or prefix) to compare against.
+        #[arg(long)]
+        baseline: String,
+        /// Commit (or prefix) to compare, the most recently recorded one by default.
+        #[arg(long)]
+        candidate: Option<String>,
+        /// Smallest relative change of the median that counts, in percent.
+        #[arg(long, default_value_t = 2.0)]
+        threshold: f64,
+        /// JSON-lines file written by `run`.
+        #[arg(long, default_value = "bench-history.jsonl")]
+        history: PathBuf,
+    },
+    /// Create `src/yYEAR/dayN.rs` and register it with the library and the registry.
+    New {
+        /// The year of the day, which gets its `yYEAR` module if it is the first one.
+        #[arg(long)]
+        year: u16,
+        /// The day to create.
+        #[arg(long)]
+        day: u8,
+        /// Root of the repository.
+        #[arg(long, default_value = ".")]
+        root: PathBuf,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum TraceFormat {
+    /// One `kind name=value ... total=N` line per step.
+    Text,
+    /// One JSON object per line.
+    Json,
+}
+
+fn main() -> ExitCode {
+    match Cli::parse().command {
+        Command::Run {
+            year,
+            day,
+            part,
+            implementation,
+            iterations,
+            phases,
+            memory,
+            inputs,
+            history,
+            no_record,
+            render,
+            render_dir,
+            trace,
+            trace_dir,
+        } => run(
+            &solutions(year, day, part, implementation.as_deref()),
+            implementation.as_deref(),
+            iterations.max(1),
+            phases,
+            memory,
+            &inputs,
+            (!no_record).then_some(history.as_path()),
+            render.map(|format| (format, render_dir.as_path())),
+            trace.map(|format| (format, trace_dir.as_path())),
+        ),
+        Command::Verify {
+            year,
+            day,
+            part,
+            inputs,
+            examples,
+        } => verify(year, day, part, &inputs, &examples),
+        Command::Extract {
+            year,
+            day,
+            page,
+            examples,
+        } => {
+            let extracted = fs::read_to_string(&page)
+                .map_err(|err| err.to_string())
+                .and_then(|html| extract::extract(&html))
+                .and_then(|extracted| {
+                    extract::write(&examples, year, day, &extracted).map_err(|err| err.to_string())
+                });
+            match extracted {
+                Ok(written) => {
+                    written.iter().for_each(|path| println!("wrote {path}"));
+                    ExitCode::SUCCESS
+                }
+                Err(err) => {
+                    eprintln!("cannot extract the examples of {}: {err}", page.display());
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Command::Synthesize {
+            year,
+            day,
+            inputs,
+            seed,
+            out,
+        } => synthesize(year, day, &inputs, seed, &out),
+        Command::Watch {
+            year,
+            day,
+            part,
+            iterations,
+            inputs,
+            root,
+            interval,
+        } => {
+            let watch = watch::Watch {
+                root: &root,
+                year,
+                day,
+                part,
+                iterations: iterations.max(1),
+                inputs: &inputs,
+                interval: Duration::from_millis(interval),
+            };
+            if let Err(err) = watch::watch(&watch) {
+                eprintln!("cannot watch {}: {err}", root.display());
+            }
+            ExitCode::FAILURE
+        }
+        Command::Compare {
+            baseline,
+            candidate,
+            threshold,
+            history,
+        } => compare(&baseline, candidate.as_deref(), threshold / 100.0, &history),
+        Command::New { year, day, root } => match scaffold::scaffold(&root, year, day) {
+            Ok(steps) => {
+                steps.iter().for_each(|step| println!("{step}"));
+                ExitCode::SUCCESS
+            }
+            Err(err) => {
+                eprintln!("cannot scaffold {year} day {day}: {err}");
+                ExitCode::FAILURE
+            }
+        },
+    }
+}
+
+fn solutions(
+    year: Option<u16>,
+    day: Option<u8>,
+    part: Option<u8>,
+    implementation: Option<&str>,
+) -> Vec<&'static Solution> {
+    registry::SOLUTIONS
+        .iter()
+        .filter(|s| year.is_none_or(|year| s.year == year))
+        .filter(|s| day.is_none_or(|day| s.day == day) && part.is_none_or(|part| s.part == part))
+        .filter(|s| implementation.is_none_or(|name| s.implementation(name).is_some()))
+        .collect()
+}
+
+/// Returns the inputs of the day of `solution` in `dir`, or `None` if there is none. Inputs that
+/// aren't [`Solution::is_valid`] are left out and set `invalid`, since the unchecked fast paths
+/// would read past them.
+fn load(solution: &Solution, dir: &Path, invalid: &mut bool) -> Option<Vec<Input>> {
+    match inputs::load(dir, solution.year, solution.day) {
+        Ok(inputs) if !inputs.is_empty() => {
+            let (valid, rejected): (Vec<_>, Vec<_>) = inputs
+                .into_iter()
+                .partition(|input| (solution.is_valid)(&input.text));
+            for input in &rejected {
+                eprintln!(
+                    "{solution}: skipping {}, it is not a valid input",
+                    input.name
+                );
+                *invalid = true;
+            }
+            (!valid.is_empty()).then_some(valid)
+        }
+        Ok(_) => {
+            eprintln!("{solution}: no input in {}", dir.display());
+            None
+        }
+        Err(err) => {
+            eprintln!("{solution}: cannot read the inputs: {err}");
+            None
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn run(
+    solutions: &[&Solution],
+    implementation: Option<&str>,
+    iterations: u32,
+    phases: bool,
+    memory: bool,
+    dir: &Path,
+    history: Option<&Path>,
+    render: Option<(Format, &Path)>,
+    trace: Option<(TraceFormat, &Path)>,
+) -> ExitCode {
+    if solutions.is_empty() {
+        eprintln!("no solution matches the given year, day, part and implementation");
+        return ExitCode::FAILURE;
+    }
+    if render.is_some() && !solutions.iter().any(|solution| is_rendered(solution)) {
+        eprintln!("--render only draws 2024 day 4, which is not selected");
+    }
+
+    let (commit, cpu) = (history::commit(), history::cpu());
+    let mut records = Vec::new();
+    let mut failed = false;
+    for solution in solutions {
+        let Some(inputs) = load(solution, dir, &mut failed) else {
+            continue;
+        };
+        // Freshly scaffolded days panic in their `todo!()` stubs, which should not stop the other days.
+        match panic::catch_unwind(|| {
+            report(
+                solution,
+                implementation,
+                &inputs,
+                iterations,
+                phases,
+                memory,
+            )
+        }) {
+            Ok(Ok(timings)) => records.extend(timings.into_iter().map(|timing| {
+                Record::new(
+                    &commit,
+                    &cpu,
+                    solution,
+                    timing.implementation,
+                    &timing.input,
+                    timing.answer,
+                    timing.stats,
+                )
+            })),
+            Ok(Err(mismatch)) => {
+                eprintln!("{solution}: {mismatch}");
+                failed = true;
+            }
+            Err(_) => {
+                eprintln!("{solution}: panicked");
+                failed = true;
+            }
+        }
+        if let Some((format, render_dir)) = render.filter(|_| is_rendered(solution)) {
+            if let Err(err) = self::render(solution, &inputs, format, render_dir) {
+                eprintln!("cannot render to {}: {err}", render_dir.display());
+                return ExitCode::FAILURE;
+            }
+        }
+        if let Some((format, trace_dir)) = trace {
+            match self::trace(solution, &inputs, format, trace_dir) {
+                Ok(true) => {}
+                Ok(false) => failed = true,
+                Err(err) => {
+                    eprintln!("cannot trace to {}: {err}", trace_dir.display());
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+    }
+
+    if let Some(path) = history.filter(|_| !records.is_empty()) {
+        if let Err(err) = history::append(path, &records) {
+            eprintln!("cannot record the timings to {}: {err}", path.display());
+            return ExitCode::FAILURE;
+        }
+    }
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Writes a synthetic input shaped like `dir/YEAR/dayN.txt` to `out`, with the answers of the
+/// default implementations.
+fn synthesize(year: u16, day: u8, dir: &Path, seed: u64, out: &Path) -> ExitCode {
+    let path = dir.join(format!("{year}/day{day}.txt"));
+    let synthetic = fs::read_to_string(&path)
+        .map_err(|err| err.to_string())
+        .and_then(|input| synthesize::synthesize(year, day, &input, seed));
+    let synthetic = match synthetic {
+        Ok(synthetic) => synthetic,
+        Err(err) => {
+            eprintln!("cannot synthesize {}: {err}", path.display());
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let answers = [1, 2].map(|part| {
+        let solution = registry::find(year, day, part).expect("every day registers both parts");
+        solution.run(&synthetic)
+    });
+    match synthesize::write(out, year, day, &synthetic, answers) {
+        Ok(written) => {
+            println!(
+                "wrote {} with answers {} and {}",
+                written.display(),
+                answers[0],
+                answers[1]
+            );
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("cannot write to {}: {err}", out.display());
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn is_rendered(solution: &Solution) -> bool {
+    (solution.year, solution.day) == (2024, 4)
+}
+
+/// Writes `INPUT-partN.EXT` to `dir` for every input, such as `2024-day4-part1.svg`, and prints
+/// how many matches it shows next to the answer of the default implementation.
+fn render(
+    solution: &Solution,
+    inputs: &[Input],
+    format: Format,
+    dir: &Path,
+) -> std::io::Result<()> {
+    fs::create_dir_all(dir)?;
+    let default = &solution.implementations[0];
+    for input in inputs {
+        let answer = (default.run)(&input.text);
+        let matches = match solution.part {
+            1 => day4::part1_matches(&input.text).count(),
+            _ => day4::part2_matches(&input.text).count(),
+        };
+        let stem = input.name.trim_end_matches(".txt").replace('/', "-");
+        let path = dir.join(format!(
+            "{stem}-part{}.{}",
+            solution.part,
+            format.extension()
+        ));
+        match format {
+            Format::Svg => {
+                let caption = format!(
+                    "{solution} {}: {matches} matches, {}: {answer}",
+                    input.name, default.name
+                );
+                fs::write(&path, render::svg(&input.text, solution.part, &caption))?
+            }
+            Format::Ppm => fs::write(&path, render::ppm(&input.text, solution.part))?,
+        }
+        println!(
+            "{solution} rendered {} ({matches} matches, {}: {answer})",
+            path.display(),
+            default.name
+        );
+    }
+    Ok(())
+}
+
+/// Writes the steps of every valid input to `dir` as `INPUT-partN.txt` or `.jsonl`, and returns
+/// whether each trace ends on the answer of the default implementation.
+fn trace(
+    solution: &Solution,
+    inputs: &[Input],
+    format: TraceFormat,
+    dir: &Path,
+) -> std::io::Result<bool> {
+    let default = &solution.implementations[0];
+    let mut agree = true;
+    for input in inputs {
+        if !(solution.is_valid)(&input.text) {
+            eprintln!(
+                "{solution}: not tracing {}, it is not a valid input",
+                input.name
+            );
+            continue;
+        }
+        let Some(steps) = trace::trace(solution.year, solution.day, solution.part, &input.text)
+        else {
+            eprintln!("{solution}: no trace");
+            return Ok(true);
+        };
+
+        fs::create_dir_all(dir)?;
+        let stem = input.name.trim_end_matches(".txt").replace('/', "-");
+        let extension = match format {
+            TraceFormat::Text => "txt",
+            TraceFormat::Json => "jsonl",
+        };
+        let path = dir.join(format!("{stem}-part{}.{extension}", solution.part));
+        let mut file = BufWriter::new(fs::File::create(&path)?);
+        let (mut count, mut total) = (0, Answer::from(0));
+        for step in steps {
+            match format {
+                TraceFormat::Text => writeln!(file, "{step}")?,
+                TraceFormat::Json => {
+                    serde_json::to_writer(&mut file, &step)?;
+                    writeln!(file)?;
+                }
+            }
+            count += 1;
+            total = step.total;
+        }
+        file.flush()?;
+
+        let answer = (default.run)(&input.text);
+        println!(
+            "{solution} traced {} ({count} steps, total {total}, {}: {answer})",
+            path.display(),
+            default.name
+        );
+        if total != answer {
+            eprintln!(
+                "{solution}: the trace of {} ends on {total}, but {} answers {answer}",
+                input.name, default.name
+            );
+            agree = false;
+        }
+    }
+    Ok(agree)
+}
+
+fn verify(
+    year: Option<u16>,
+    day: Option<u8>,
+    part: Option<u8>,
+    dir: &Path,
+    examples_dir: &Path,
+) -> ExitCode {
+    let solutions = solutions(year, day, part, None);
+    if solutions.is_empty() {
+        eprintln!("no solution matches the given year, day and part");
+        return ExitCode::FAILURE;
+    }
+
+    let mut failed = false;
+    for solution in solutions {
+        let Some(inputs) = load(solution, dir, &mut failed) else {
+            failed = true;
+            continue;
+        };
+        let examples = match inputs::load(examples_dir, solution.year, solution.day) {
+            Ok(examples) => examples,
+            Err(err) => {
+                eprintln!("{solution}: cannot read the examples: {err}");
+                failed = true;
+                continue;
+            }
+        };
+        // Examples are smaller than the inputs the fast paths are written for.
+        let examples: Vec<_> = examples
+            .into_iter()
+            .filter(|example| (solution.is_valid)(&example.text))
+            .map(|example| Input {
+                name: format!("{}/{}", examples_dir.display(), example.name),
+                ..example
+            })
+            .collect();
+        let all: Vec<_> = inputs.iter().chain(&examples).cloned().collect();
+        match panic::catch_unwind(|| check(solution, &all)) {
+            Ok(Ok(())) => println!(
+                "{solution}: ok (implementations: {}, inputs: {}, examples: {}, known answers: {})",
+                solution.implementations.len(),
+                inputs.len(),
+                examples.len(),
+                all.iter()
+                    .filter(|input| input.answer(solution.part).is_some())
+                    .count()
+            ),
+            Ok(Err(mismatch)) => {
+                eprintln!("{solution}: {mismatch}");
+                failed = true;
+            }
+            Err(_) => {
+                eprintln!("{solution}: panicked");
+                failed = true;
+            }
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn compare(baseline: &str, candidate: Option<&str>, threshold: f64, path: &Path) -> ExitCode {
+    let records = match history::load(path) {
+        Ok(records) => records,
+        Err(err) => {
+            eprintln!("cannot read {}: {err}", path.display());
+            return ExitCode::FAILURE;
+        }
+    };
+    let (Some(baseline), Some(candidate)) = (
+        history::resolve(&records, Some(baseline)),
+        history::resolve(&records, candidate),
+    ) else {
+        eprintln!("no recorded run matches the given commits");
+        return ExitCode::FAILURE;
+    };
+
+    let comparisons = history::compare(&records, baseline, candidate, threshold);
+    if comparisons.is_empty() {
+        eprintln!("{baseline} and {candidate} have no benchmark on the same CPU in common");
+        return ExitCode::FAILURE;
+    }
+
+    println!("{baseline} -> {candidate}");
+    for comparison in &comparisons {
+        let (base, next) = (comparison.baseline, comparison.candidate);
+        println!(
+            "{} day {} part {} {:<8} {:<16} {:>10.2?} -> {:>10.2?} {:>+8.1}%  {}",
+            next.year,
+            next.day,
+            next.part,
+            format!("{}:", next.implementation),
+            next.input,
+            Duration::from_nanos(base.median_ns),
+            Duration::from_nanos(next.median_ns),
+            comparison.change * 100.0,
+            match comparison.verdict {
+                Verdict::Regression => "regression",
+                Verdict::Improvement => "improvement",
+                Verdict::Unchanged => "unchanged",
+            }
+        );
+    }
+
+    if comparisons
+        .iter()
+        .any(|comparison| comparison.verdict == Verdict::Regression)
+    {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Checks that every implementation gives the same answer on every input, and the known answer
+/// where there is one.
+fn check(solution: &Solution, inputs: &[Input]) -> Result<(), String> {
+    let default = &solution.implementations[0];
+    for input in inputs {
+        let answer = (default.run)(&input.text);
+        if let Some(expected) = input
+            .answer(solution.part)
+            .filter(|expected| *expected != answer)
+        {
+            return Err(format!(
+                "{}: {} answered {answer}, but the answer is {expected}",
+                input.name, default.name
+            ));
+        }
+        for other in &solution.implementations[1..] {
+            let other_answer = (other.run)(&input.text);
+            if other_answer != answer {
+                return Err(format!(
+                    "{}: {} answered {other_answer}, but {} answered {answer}",
+                    input.name, other.name, default.name
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+struct Timing {
+    implementation: &'static str,
+    /// The name of the input, or `all` for solving every input in a row.
+    input: String,
+    /// The answer to the input, or `None` for `all`.
+    answer: Option<Answer>,
+    stats: Stats,
+}
+
+/// Checks the answers, then times the selected implementations on each input and, if there are
+/// several, on all of them in a row.
+fn report(
+    solution: &Solution,
+    implementation: Option<&str>,
+    inputs: &[Input],
+    iterations: u32,
+    phases: bool,
+    memory: bool,
+) -> Result<Vec<Timing>, String> {
+    check(solution, inputs)?;
+
+    let mut timings = Vec::new();
+    for selected in solution
+        .implementations
+        .iter()
+        .filter(|imp| implementation.is_none_or(|name| imp.name == name))
+    {
+        let label = format!("{}:", selected.name);
+        for input in inputs {
+            let answer = (selected.run)(&input.text);
+            let stats = Stats::new(&mut samples(iterations, || {
+                black_box((selected.run)(black_box(&input.text)));
+            }));
+            println!(
+                "{solution} {label:<8} {:<16} {answer:<16} {:>10.2?}",
+                input.name, stats.median
+            );
+            timings.push(Timing {
+                implementation: selected.name,
+                input: input.name.clone(),
+                answer: Some(answer),
+                stats,
+            });
+        }
+
+        if inputs.len() > 1 {
+            let stats = Stats::new(&mut samples(iterations, || {
+                for input in inputs {
+                    black_box((selected.run)(black_box(&input.text)));
+                }
+            }));
+            println!(
+                "{solution} {label:<8} {:<16} {:<16} {:>10.2?}",
+                "all", "", stats.median
+            );
+            timings.push(Timing {
+                implementation: selected.name,
+                input: "all".to_owned(),
+                answer: None,
+                stats,
+            });
+        }
+    }
+
+    if memory {
+        for selected in solution
+            .implementations
+            .iter()
+            .filter(|imp| implementation.is_none_or(|name| imp.name == name))
+        {
+            let label = format!("{}:", selected.name);
+            for input in inputs {
+                // The first run may initialize things lazily, like the CPU feature detection.
+                black_box((selected.run)(black_box(&input.text)));
+                let (_, usage) = memory::measure(|| (selected.run)(black_box(&input.text)));
+                println!(
+                    "{solution} {label:<8} {:<16} {} allocations of {} B, peak heap {} B, \
+                     peak stack {} B",
+                    input.name, usage.allocations, usage.bytes, usage.peak_heap, usage.peak_stack
+                );
+            }
+        }
+    }
+
+    if phases {
+        for input in inputs {
+            let expected = solution.run(&input.text);
+            let mut parse = Vec::with_capacity(iterations as usize);
+            let mut solve = Vec::with_capacity(iterations as usize);
+            for _ in 0..iterations {
+                let phases = solution.phases(&input.text);
+                assert_eq!(phases.answer, expected, "phased and fused answers differ");
+                parse.push(phases.parse);
+                solve.push(phases.solve);
+            }
+            println!(
+                "{solution} phases:  {:<16} parse {:>10.2?}, solve {:>10.2?}",
+                input.name,
+                middle(&mut parse),
+                middle(&mut solve)
+            );
+        }
+    }
+    Ok(timings)
+}
+
+/// Runs `f` `iterations` times and returns every duration.
+fn samples(iterations: u32, mut f: impl FnMut()) -> Vec<Duration> {
+    (0..iterations)
+        .map(|_| {
+            let start = Instant::now();
+            f();
+            start.elapsed()
+        })
+        .collect()
+}
+
+fn middle(durations: &mut [Duration]) -> Duration {
+    durations.sort_unstable();
+    durations[durations.len() / 2]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_2024_public::registry::Implementation;
+    use std::fs;
+
+    #[test]
+    fn test_run_fails_on_panics() {
+        let dir = std::env::temp_dir().join(format!("aoc-run-panics-{}", std::process::id()));
+        fs::create_dir_all(dir.join("2024")).unwrap();
+        fs::write(dir.join("2024/day1.txt"), "3   4\n").unwrap();
+
+        let panicking = Solution {
+            year: 2024,
+            day: 1,
+            part: 1,
+            implementations: &[Implementation {
+                name: "default",
+                run: |_| todo!(),
+            }],
+            is_valid: |_| true,
+            parse: |_| todo!(),
+            solve: |_| todo!(),
+        };
+        let exit = run(&[&panicking], None, 1, false, false, &dir, None, None, None);
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(exit, ExitCode::FAILURE);
+    }
+}
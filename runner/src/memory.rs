@@ -0,0 +1,212 @@
+//! Counts the heap allocations of a solution and measures how deep its stack goes, to check that
+//! the fast paths stay allocation-free.
+//!
+//! [`Counting`] counts per thread, so solutions measured on one thread don't see the allocations
+//! of the others, such as the other tests. The stack is measured by painting the stack below the
+//! caller with a pattern before running the solution and finding the lowest byte it overwrote.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    hint::black_box,
+    ptr,
+};
+
+/// The system allocator, counting the allocations of every thread.
+pub struct Counting;
+
+#[derive(Debug, Clone, Copy)]
+struct Counts {
+    allocations: u64,
+    bytes: u64,
+    /// Bytes allocated and not freed yet. Frees of memory from other threads saturate at 0.
+    live: u64,
+    peak: u64,
+}
+
+thread_local! {
+    static COUNTS: Cell<Counts> = const {
+        Cell::new(Counts {
+            allocations: 0,
+            bytes: 0,
+            live: 0,
+            peak: 0,
+        })
+    };
+}
+
+/// Updates the counts of the current thread, unless it is being torn down.
+fn count(update: impl FnOnce(&mut Counts)) {
+    let _ = COUNTS.try_with(|counts| {
+        let mut updated = counts.get();
+        update(&mut updated);
+        counts.set(updated);
+    });
+}
+
+fn allocated(freed: usize, size: usize) {
+    count(|counts| {
+        counts.allocations += 1;
+        counts.bytes += size as u64;
+        counts.live = counts.live.saturating_sub(freed as u64) + size as u64;
+        counts.peak = counts.peak.max(counts.live);
+    });
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            allocated(0, layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            allocated(0, layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let ptr = System.realloc(ptr, layout, new_size);
+        if !ptr.is_null() {
+            allocated(layout.size(), new_size);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        count(|counts| counts.live = counts.live.saturating_sub(layout.size() as u64));
+    }
+}
+
+/// What a call did to the heap and the stack.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Usage {
+    /// Number of allocations and reallocations.
+    pub allocations: u64,
+    /// Bytes requested by them.
+    pub bytes: u64,
+    /// Most bytes allocated at once, on top of what was allocated before the call.
+    pub peak_heap: u64,
+    /// Deepest the stack went below the caller of [`measure`], at most [`STACK`].
+    pub peak_stack: usize,
+}
+
+/// Bytes of stack painted below the caller of [`measure`], more than any day uses but well within
+/// the 2 MiB the test threads get.
+pub const STACK: usize = 512 * 1024;
+
+const PAINT: u8 = 0xa5;
+
+/// Runs `f` and returns its result and what it allocated and how much stack it used. Only
+/// allocations of the current thread count, and only if [`Counting`] is the global allocator.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Usage) {
+    let bottom = paint();
+    let before = COUNTS.with(|counts| {
+        let mut before = counts.get();
+        before.peak = before.live;
+        counts.set(before);
+        before
+    });
+    let result = f();
+    let after = COUNTS.with(Cell::get);
+    // SAFETY: `paint` wrote the `STACK` bytes above `bottom`, so they are mapped, and reading
+    // stack memory that no frame owns anymore only observes whatever `f` left there.
+    let peak_stack = unsafe { scan(bottom) };
+
+    let usage = Usage {
+        allocations: after.allocations - before.allocations,
+        bytes: after.bytes - before.bytes,
+        peak_heap: after.peak - before.live,
+        peak_stack,
+    };
+    (result, usage)
+}
+
+/// Fills `STACK` bytes of stack with [`PAINT`] and returns the lowest address it filled. Called
+/// from [`measure`] like `f` is, so `f`'s frames land on the painted bytes.
+#[inline(never)]
+fn paint() -> usize {
+    let mut region = [PAINT; STACK];
+    black_box(&mut region);
+    region.as_ptr() as usize
+}
+
+/// Returns how many bytes of the painted stack above `bottom` were overwritten, counting from
+/// the first one that was.
+///
+/// # Safety
+///
+/// The `STACK` bytes above `bottom` must be mapped stack memory of the current thread.
+#[inline(never)]
+unsafe fn scan(bottom: usize) -> usize {
+    let untouched = (0..STACK)
+        .take_while(|i| ptr::read_volatile((bottom + i) as *const u8) == PAINT)
+        .count();
+    STACK - untouched
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_2024_public::{inputs, registry};
+    use std::path::Path;
+
+    #[test]
+    fn test_measure() {
+        let (len, usage) = measure(|| black_box(vec![0u8; 1000]).len());
+        assert_eq!(len, 1000);
+        assert_eq!(usage.allocations, 1);
+        assert_eq!(usage.bytes, 1000);
+        assert_eq!(usage.peak_heap, 1000);
+
+        let (_, usage) = measure(|| {
+            let mut values = black_box(Vec::<u64>::with_capacity(4));
+            values.extend(0..100);
+            values.len()
+        });
+        assert!(usage.allocations > 1);
+        assert!(usage.peak_heap >= 800 && usage.peak_heap < usage.bytes);
+
+        let (_, usage) = measure(|| black_box([1u8; 100_000])[99_999]);
+        assert_eq!(usage.allocations, 0);
+        assert!(usage.peak_stack >= 100_000 && usage.peak_stack < STACK);
+    }
+
+    #[test]
+    fn test_fast_paths_do_not_allocate() {
+        // The examples, and the synthetic inputs of the full size, which the fast paths of days 1
+        // and 4 need.
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+        for solution in registry::SOLUTIONS {
+            let mut inputs = ["examples", "synthetic"]
+                .into_iter()
+                .flat_map(|dir| inputs::load(&root.join(dir), solution.year, solution.day).unwrap())
+                .map(|input| input.text)
+                .collect::<Vec<_>>();
+            if inputs.is_empty() {
+                // A new day has nothing to measure until its examples are added.
+                continue;
+            }
+            inputs.retain(|input| (solution.is_valid)(input));
+            assert!(!inputs.is_empty(), "{solution}: no input to measure");
+
+            for implementation in solution.implementations {
+                for input in &inputs {
+                    let (_, usage) = measure(|| (implementation.run)(black_box(input)));
+                    assert_eq!(
+                        (usage.allocations, usage.bytes),
+                        (0, 0),
+                        "{solution}: {} allocated",
+                        implementation.name
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,243 @@
+//! Draws the `day4` matches over their grid, to see where a wrong count comes from.
+//!
+//! The positions come from the scalar [`day4::part1_matches`] and [`day4::part2_matches`], so a
+//! render of an input the bit-parallel solvers miscount shows what they should have found.
+
+use aoc_2024_public::y2024::day4::{self, Direction};
+use clap::ValueEnum;
+use std::fmt::Write;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// The letters, with a line through every match, coloured by direction.
+    Svg,
+    /// A heatmap of how many matches cover every cell.
+    Ppm,
+}
+
+impl Format {
+    pub fn extension(self) -> &'static str {
+        match self {
+            Format::Svg => "svg",
+            Format::Ppm => "ppm",
+        }
+    }
+}
+
+/// Size of a cell in the SVG, in pixels.
+const CELL: usize = 16;
+/// Size of a cell in the PPM, in pixels.
+const PIXELS: usize = 4;
+
+/// A match as the cells it covers, from the first to the last letter.
+struct Stroke {
+    from: (usize, usize),
+    to: (usize, usize),
+    colour: &'static str,
+    title: String,
+}
+
+fn colour(direction: Direction) -> &'static str {
+    match direction {
+        Direction::East => "#e6194b",
+        Direction::West => "#3cb44b",
+        Direction::South => "#4363d8",
+        Direction::North => "#f58231",
+        Direction::SouthEast => "#911eb4",
+        Direction::SouthWest => "#42d4f4",
+        Direction::NorthEast => "#f032e6",
+        Direction::NorthWest => "#9a6324",
+    }
+}
+
+/// Returns the strokes for the matches `part` counts in `input`. An `X-MAS` is two strokes.
+fn strokes(input: &str, part: u8) -> Vec<Stroke> {
+    if part == 1 {
+        return day4::part1_matches(input)
+            .map(|m| {
+                let (dr, dc) = m.direction.offset();
+                Stroke {
+                    from: (m.row, m.col),
+                    to: (
+                        m.row.wrapping_add_signed(3 * dr),
+                        m.col.wrapping_add_signed(3 * dc),
+                    ),
+                    colour: colour(m.direction),
+                    title: format!("XMAS at {}, {} towards {:?}", m.row, m.col, m.direction),
+                }
+            })
+            .collect();
+    }
+    day4::part2_matches(input)
+        .flat_map(|(row, col)| {
+            let title = format!("X-MAS around {row}, {col}");
+            [
+                ((row - 1, col - 1), (row + 1, col + 1)),
+                ((row - 1, col + 1), (row + 1, col - 1)),
+            ]
+            .map(|(from, to)| Stroke {
+                from,
+                to,
+                colour: "#e6194b",
+                title: title.clone(),
+            })
+        })
+        .collect()
+}
+
+/// Returns the cells on the straight line from `from` to `to`, both included.
+fn cells(stroke: &Stroke) -> impl Iterator<Item = (usize, usize)> {
+    let (from, to) = (stroke.from, stroke.to);
+    let step = |a: usize, b: usize| (b as isize - a as isize).signum();
+    let (dr, dc) = (step(from.0, to.0), step(from.1, to.1));
+    let len = from.0.abs_diff(to.0).max(from.1.abs_diff(to.1));
+    (0..=len as isize).map(move |i| {
+        (
+            from.0.wrapping_add_signed(dr * i),
+            from.1.wrapping_add_signed(dc * i),
+        )
+    })
+}
+
+/// Returns how many strokes cross every cell, row by row.
+fn density(rows: &[&str], strokes: &[Stroke]) -> Vec<Vec<u32>> {
+    let mut density: Vec<_> = rows.iter().map(|row| vec![0; row.len()]).collect();
+    for stroke in strokes {
+        for (row, col) in cells(stroke) {
+            density[row][col] += 1;
+        }
+    }
+    density
+}
+
+/// Renders the matches `part` counts in `input` as an SVG, captioned with `caption`.
+pub fn svg(input: &str, part: u8, caption: &str) -> String {
+    let rows: Vec<_> = input.lines().collect();
+    let strokes = strokes(input, part);
+    let density = density(&rows, &strokes);
+    let width = rows.iter().map(|row| row.len()).max().unwrap_or(0) * CELL;
+    let height = (rows.len() + 2) * CELL;
+    let centre = |(row, col): (usize, usize)| (col * CELL + CELL / 2, (row + 2) * CELL + CELL / 2);
+
+    let mut svg = String::new();
+    writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}">"#
+    )
+    .unwrap();
+    svg.push_str(concat!(
+        "<style>text{font:12px monospace;text-anchor:middle;dominant-baseline:central;fill:#bbb}",
+        "text.hit{fill:#000;font-weight:bold}text.caption{text-anchor:start;fill:#000}",
+        "line{stroke-width:4;stroke-linecap:round;stroke-opacity:0.45}</style>\n",
+        "<rect width=\"100%\" height=\"100%\" fill=\"#fff\"/>\n"
+    ));
+    writeln!(
+        svg,
+        r#"<text class="caption" x="4" y="{}">{}</text>"#,
+        CELL / 2,
+        escape(caption)
+    )
+    .unwrap();
+
+    for stroke in &strokes {
+        let ((x1, y1), (x2, y2)) = (centre(stroke.from), centre(stroke.to));
+        writeln!(
+            svg,
+            r#"<line x1="{x1}" y1="{y1}" x2="{x2}" y2="{y2}" stroke="{}"><title>{}</title></line>"#,
+            stroke.colour, stroke.title
+        )
+        .unwrap();
+    }
+    for (row, letters) in rows.iter().enumerate() {
+        for (col, letter) in letters.chars().enumerate() {
+            let (x, y) = centre((row, col));
+            let class = if density[row].get(col).is_some_and(|n| *n > 0) {
+                r#" class="hit""#
+            } else {
+                ""
+            };
+            let mut buf = [0; 4];
+            writeln!(
+                svg,
+                r#"<text x="{x}" y="{y}"{class}>{}</text>"#,
+                escape(letter.encode_utf8(&mut buf))
+            )
+            .unwrap();
+        }
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// Renders how many matches `part` counts in `input` cover every cell as a binary PPM, from black
+/// for none through red and yellow to white for the most covered cells.
+pub fn ppm(input: &str, part: u8) -> Vec<u8> {
+    let rows: Vec<_> = input.lines().collect();
+    let density = density(&rows, &strokes(input, part));
+    let max = density.iter().flatten().copied().max().unwrap_or(0).max(1) as f32;
+    let (width, height) = (
+        rows.iter().map(|row| row.len()).max().unwrap_or(0),
+        rows.len(),
+    );
+
+    let mut ppm = format!("P6\n{} {}\n255\n", width * PIXELS, height * PIXELS).into_bytes();
+    for row in &density {
+        let line: Vec<u8> = (0..width)
+            .flat_map(|col| {
+                let t = row.get(col).copied().unwrap_or(0) as f32 / max;
+                let channel = |offset: f32| ((3.0 * t - offset).clamp(0.0, 1.0) * 255.0) as u8;
+                [channel(0.0), channel(1.0), channel(2.0)].repeat(PIXELS)
+            })
+            .collect();
+        for _ in 0..PIXELS {
+            ppm.extend_from_slice(&line);
+        }
+    }
+    ppm
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "XMAS.M.S\n......A.\nXMAS.M.S\n";
+
+    #[test]
+    fn test_svg() {
+        let svg = svg(INPUT, 1, "day 4 part 1 <example>");
+        assert!(svg.starts_with("<svg ") && svg.ends_with("</svg>\n"));
+        assert!(svg.contains(">day 4 part 1 &lt;example&gt;</text>"));
+        assert_eq!(svg.matches("<line ").count(), 2);
+        assert!(svg.contains("<title>XMAS at 0, 0 towards East</title>"));
+        // Letters of a match are highlighted, the others are not.
+        assert!(svg.contains(r#"<text x="8" y="40" class="hit">X</text>"#));
+        assert!(svg.contains(r#"<text x="72" y="40">.</text>"#));
+
+        let svg = super::svg(INPUT, 2, "");
+        assert_eq!(svg.matches("<line ").count(), 2);
+        assert!(svg.contains("<title>X-MAS around 1, 6</title>"));
+    }
+
+    #[test]
+    fn test_ppm() {
+        let ppm = ppm(INPUT, 1);
+        let header = b"P6\n32 12\n255\n";
+        assert!(ppm.starts_with(header));
+        assert_eq!(ppm.len(), header.len() + 32 * 12 * 3);
+
+        let pixel = |row: usize, col: usize| {
+            let at = header.len() + (row * PIXELS * 32 + col * PIXELS) * 3;
+            &ppm[at..at + 3]
+        };
+        // The `XMAS` rows are covered once, the `.` are not covered at all.
+        assert_eq!(pixel(0, 0), [255, 255, 255]);
+        assert_eq!(pixel(0, 4), [0, 0, 0]);
+        assert_eq!(pixel(1, 0), [0, 0, 0]);
+    }
+}
@@ -0,0 +1,161 @@
+//! Generates a new `yYEAR::dayN` module and registers it with the library and the registry, which
+//! the runner, the server and the benches read the days from. The first day of a year also creates
+//! the `yYEAR` module.
+//!
+//! Every step checks whether it already happened, so running the generator twice is harmless.
+
+use std::{fs, io, path::Path};
+
+const TEMPLATE: &str = include_str!("day.rs.in");
+
+/// Scaffolds `day` of `year` in the repository at `root` and returns a line describing each step.
+pub fn scaffold(root: &Path, year: u16, day: u8) -> io::Result<Vec<String>> {
+    let (year_module, module) = (format!("y{year}"), format!("day{day}"));
+    let mut steps = Vec::new();
+
+    fs::create_dir_all(root.join(format!("src/{year_module}")))?;
+    let mut create = |file: String, text: String| {
+        if root.join(&file).exists() {
+            steps.push(format!("{file} already exists"));
+        } else {
+            fs::write(root.join(&file), text)?;
+            steps.push(format!("created {file}"));
+        }
+        io::Result::Ok(())
+    };
+    create(
+        format!("src/{year_module}/{module}.rs"),
+        TEMPLATE
+            .replace("{year}", &year.to_string())
+            .replace("{day}", &day.to_string()),
+    )?;
+    create(
+        format!("src/{year_module}/mod.rs"),
+        format!("//! The solutions of Advent of Code {year}.\n\n"),
+    )?;
+
+    let mut register =
+        |file: &str, module: &str, edit: &dyn Fn(&str) -> io::Result<Option<String>>| {
+            let path = root.join(file);
+            match edit(&fs::read_to_string(&path)?)? {
+                Some(text) => {
+                    fs::write(&path, text)?;
+                    steps.push(format!("registered {module} in {file}"));
+                }
+                None => steps.push(format!("{file} already registers {module}")),
+            }
+            io::Result::Ok(())
+        };
+
+    register("src/lib.rs", &year_module, &|text| {
+        insert_after_last(text, "pub mod y", &[format!("pub mod {year_module};")])
+    })?;
+    register(&format!("src/{year_module}/mod.rs"), &module, &|text| {
+        let line = format!("pub mod {module};");
+        if text.lines().any(|line| line.starts_with("pub mod day")) {
+            insert_after_last(text, "pub mod day", &[line])
+        } else {
+            Ok(Some(format!("{text}{line}\n")))
+        }
+    })?;
+    register(
+        "src/registry.rs",
+        &format!("{year_module}::{module}"),
+        &|text| {
+            // Days of a new year go after the last day of the years before.
+            let prefix = format!("    {year_module}::");
+            let prefix = if text.lines().any(|line| line.starts_with(&prefix)) {
+                prefix.as_str()
+            } else {
+                "    y"
+            };
+            insert_after_last(text, prefix, &[format!("    {year_module}::{module},")])
+        },
+    )?;
+
+    Ok(steps)
+}
+
+/// Inserts `lines` after the last line starting with `prefix`, unless the first of them is already
+/// there.
+fn insert_after_last(text: &str, prefix: &str, lines: &[String]) -> io::Result<Option<String>> {
+    insert(text, prefix, lines, |result| {
+        result
+            .iter()
+            .rposition(|line| line.starts_with(prefix))
+            .map(|last| last + 1)
+    })
+}
+
+fn insert(
+    text: &str,
+    prefix: &str,
+    lines: &[String],
+    at: impl Fn(&[String]) -> Option<usize>,
+) -> io::Result<Option<String>> {
+    let mut result = text.lines().map(str::to_owned).collect::<Vec<_>>();
+    if result
+        .iter()
+        .any(|line| line.trim_end_matches(',') == lines[0].trim_end_matches(','))
+    {
+        return Ok(None);
+    }
+
+    let Some(at) = at(&result) else {
+        let prefix = prefix.trim();
+        return Err(io::Error::other(format!("no line starts with `{prefix}`")));
+    };
+    result.splice(at..at, lines.iter().cloned());
+    Ok(Some(result.join("\n") + "\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scaffold_is_idempotent() {
+        let repo = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        fs::create_dir_all(root.join("src/y2024")).unwrap();
+        for file in ["src/lib.rs", "src/registry.rs", "src/y2024/mod.rs"] {
+            fs::copy(repo.join(file), root.join(file)).unwrap();
+        }
+        let files = [
+            "src/lib.rs",
+            "src/registry.rs",
+            "src/y2024/mod.rs",
+            "src/y2030/mod.rs",
+        ];
+
+        scaffold(&root, 2024, 25).unwrap();
+        let steps = scaffold(&root, 2030, 1).unwrap();
+        assert!(
+            steps.iter().all(|step| !step.contains("already")),
+            "{steps:?}"
+        );
+        let snapshot = files.map(|file| fs::read_to_string(root.join(file)).unwrap());
+        assert!(fs::read_to_string(root.join("src/y2024/day25.rs"))
+            .unwrap()
+            .contains("day! {\n    year: 2024,\n    day: 25,\n"));
+        assert!(root.join("src/y2030/day1.rs").exists());
+        assert!(snapshot[0].contains("pub mod y2024;\npub mod y2030;\n"));
+        assert!(snapshot[1].contains("    y2024::day4,\n    y2024::day25,\n    y2030::day1,\n};"));
+        assert!(snapshot[2].ends_with("pub mod day4;\npub mod day25;\n"));
+        assert_eq!(
+            snapshot[3],
+            "//! The solutions of Advent of Code 2030.\n\npub mod day1;\n"
+        );
+
+        let steps = scaffold(&root, 2030, 1).unwrap();
+        assert!(
+            steps.iter().all(|step| step.contains("already")),
+            "{steps:?}"
+        );
+        for (file, before) in files.iter().zip(snapshot) {
+            assert_eq!(fs::read_to_string(root.join(file)).unwrap(), before);
+        }
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
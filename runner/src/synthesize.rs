@@ -0,0 +1,404 @@
+//! Generates synthetic inputs shaped like a private one, which, unlike the puzzle inputs, can be
+//! committed as test fixtures and benchmark inputs.
+//!
+//! Each day keeps what its solutions rely on and what drives the size of its answers, and
+//! randomizes the rest:
+//!
+//! - 2024 day 1 maps every distinct number to a new random one in the same range, which keeps
+//!   the 5-digit columns and which numbers repeat and match.
+//! - 2024 day 2 draws new reports with the same number of levels, with how many are safe and safe
+//!   with a level removed drawn from the seed alone.
+//! - 2024 day 3 keeps every instruction where it is, draws new `mul` operands with as many digits,
+//!   and shuffles the corrupted memory between the instructions.
+//! - 2024 day 4 shuffles the letters of the grid.
+
+use aoc_2024_public::{
+    registry,
+    y2024::{day2, day3},
+    Answer,
+};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// How many times a day may draw something that turns out to break the shape of the input.
+const ATTEMPTS: usize = 1000;
+
+/// A linear congruential generator, reproducible from its seed on every platform.
+struct Random(u64);
+
+impl Random {
+    fn below(&mut self, n: usize) -> usize {
+        self.0 = self
+            .0
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        ((self.0 >> 33) % n as u64) as usize
+    }
+
+    fn between(&mut self, low: u32, high: u32) -> u32 {
+        low + self.below((high - low + 1) as usize) as u32
+    }
+
+    fn shuffle<T>(&mut self, values: &mut [T]) {
+        for i in (1..values.len()).rev() {
+            values.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+/// Returns a synthetic input for `day` of `year` shaped like `input`, drawn from `seed`.
+pub fn synthesize(year: u16, day: u8, input: &str, seed: u64) -> Result<String, String> {
+    let solution =
+        registry::find(year, day, 1).ok_or_else(|| format!("{year} day {day} is not solved"))?;
+    if !(solution.is_valid)(input) {
+        return Err(format!("the input is not a valid {year} day {day} input"));
+    }
+
+    let mut random = Random(seed);
+    let mut error = "it is invalid or the same as the input".to_owned();
+    for _ in 0..ATTEMPTS {
+        let synthetic = match (year, day) {
+            (2024, 1) => Ok(day1(input, &mut random)),
+            (2024, 2) => day2(input, &mut random),
+            (2024, 3) => day3(input, &mut random),
+            (2024, 4) => Ok(day4(input, &mut random)),
+            _ => return Err(format!("{year} day {day} has no synthesizer")),
+        };
+        match synthetic {
+            Ok(synthetic) if (solution.is_valid)(&synthetic) && synthetic != input => {
+                return Ok(synthetic)
+            }
+            Ok(_) => {}
+            Err(drawn) => error = drawn,
+        }
+    }
+    Err(format!(
+        "no synthetic {year} day {day} input could be drawn: {error}"
+    ))
+}
+
+fn day1(input: &str, random: &mut Random) -> String {
+    let numbers = input
+        .split_ascii_whitespace()
+        .map(|number| number.parse::<u32>().unwrap())
+        .collect::<Vec<_>>();
+    let (low, high) = (
+        *numbers.iter().min().unwrap(),
+        *numbers.iter().max().unwrap(),
+    );
+
+    let mut relabeled = BTreeMap::new();
+    let mut used = BTreeSet::new();
+    for number in &numbers {
+        relabeled.entry(*number).or_insert_with(|| loop {
+            let label = random.between(low, high);
+            if used.insert(label) {
+                break label;
+            }
+        });
+    }
+    numbers
+        .chunks(2)
+        .map(|pair| format!("{}   {}\n", relabeled[&pair[0]], relabeled[&pair[1]]))
+        .collect()
+}
+
+/// Whether a 2024 day 2 report is safe, safe with a level removed, or unsafe.
+fn safety(report: &day2::Report) -> usize {
+    match (report.is_safe, report.is_tolerated()) {
+        (true, _) => 0,
+        (false, true) => 1,
+        (false, false) => 2,
+    }
+}
+
+fn day2(input: &str, random: &mut Random) -> Result<String, String> {
+    let levels = input
+        .split_ascii_whitespace()
+        .map(|level| level.parse::<u32>().unwrap());
+    let (low, high) = (levels.clone().min().unwrap(), levels.max().unwrap());
+    let lengths = day2::reports(input)
+        .map(|report| report.levels.len())
+        .collect::<Vec<_>>();
+    let longest = *lengths.iter().max().unwrap();
+    if (high - low) as usize + 1 < longest {
+        return Err(format!(
+            "no safe report of {longest} levels fits between {low} and {high}"
+        ));
+    }
+
+    let safe = |len: usize, random: &mut Random| loop {
+        let step = |random: &mut Random| random.between(1, 3) as i64;
+        let sign = [-1, 1][random.below(2)];
+        let mut level = random.between(low, high) as i64;
+        let mut report = vec![level];
+        for _ in 1..len {
+            level += sign * step(random);
+            report.push(level);
+        }
+        if report
+            .iter()
+            .all(|level| (low as i64..=high as i64).contains(level))
+        {
+            break report;
+        }
+    };
+
+    // How many reports are safe and safe with a level removed comes from the seed alone, so the
+    // answers tell nothing about the private ones.
+    let count = lengths.len();
+    let safe_count = random.between(count as u32 / 5, 2 * count as u32 / 5) as usize;
+    let tolerated_count = random.between(count as u32 / 10, count as u32 / 5) as usize;
+    let mut targets = (0..count)
+        .map(|i| match i {
+            _ if i < safe_count => 0,
+            _ if i < safe_count + tolerated_count => 1,
+            _ => 2,
+        })
+        .collect::<Vec<_>>();
+    random.shuffle(&mut targets);
+
+    let mut synthetic = String::with_capacity(input.len());
+    for (len, target) in lengths.into_iter().zip(targets) {
+        let mut drawn = None;
+        for _ in 0..ATTEMPTS {
+            let mut levels = safe(len, random);
+            // A report safe with one level removed has one level out of line, an unsafe one two,
+            // a little off like the puzzle's.
+            for _ in 0..target {
+                let level = &mut levels[random.below(len)];
+                *level = (*level + random.between(0, 12) as i64 - 6).clamp(low as i64, high as i64);
+            }
+            let line = levels
+                .iter()
+                .map(i64::to_string)
+                .collect::<Vec<_>>()
+                .join(" ");
+            if safety(&day2::reports(&line).next().unwrap()) == target {
+                drawn = Some(line);
+                break;
+            }
+        }
+        let line = drawn.ok_or("no report of the wanted safety could be drawn")?;
+        synthetic.push_str(&line);
+        synthetic.push('\n');
+    }
+    Ok(synthetic)
+}
+
+fn day3(input: &str, random: &mut Random) -> Result<String, String> {
+    if !input.is_ascii() {
+        return Err("the input is not ASCII".to_owned());
+    }
+    let mut synthetic = input.as_bytes().to_vec();
+    let mut junk_start = 0;
+    for (start, instruction) in day3::instructions(input) {
+        let end = start + input[start..].find(')').unwrap() + 1;
+        if let day3::Instruction::Mul(..) = instruction {
+            for operand in input[start + "mul(".len()..end - 1].split(',') {
+                let at = operand.as_ptr() as usize - input.as_ptr() as usize;
+                let digits = operand.len() as u32;
+                let low = if digits == 1 {
+                    0
+                } else {
+                    10u32.pow(digits - 1)
+                };
+                let drawn = random.between(low, 10u32.pow(digits) - 1);
+                synthetic[at..at + operand.len()].copy_from_slice(drawn.to_string().as_bytes());
+            }
+        }
+        shuffle_junk(&mut synthetic[junk_start..start], random);
+        junk_start = end;
+    }
+    shuffle_junk(&mut synthetic[junk_start..], random);
+
+    let synthetic = String::from_utf8(synthetic).unwrap();
+    let kinds = |text: &str| {
+        day3::instructions(text)
+            .map(|(start, instruction)| (start, std::mem::discriminant(&instruction)))
+            .collect::<Vec<_>>()
+    };
+    if kinds(&synthetic) != kinds(input) {
+        return Err("shuffling the corrupted memory changed the instructions".to_owned());
+    }
+    Ok(synthetic)
+}
+
+/// Shuffles the ASCII corrupted memory between two instructions, leaving the line breaks in
+/// place, until it contains no instruction.
+fn shuffle_junk(junk: &mut [u8], random: &mut Random) {
+    for line in junk.split_mut(|byte| *byte == b'\n') {
+        for _ in 0..ATTEMPTS {
+            random.shuffle(line);
+            if day3::instructions(std::str::from_utf8(line).unwrap())
+                .next()
+                .is_none()
+            {
+                break;
+            }
+        }
+    }
+}
+
+fn day4(input: &str, random: &mut Random) -> String {
+    let mut letters = input
+        .bytes()
+        .filter(|byte| *byte != b'\n')
+        .collect::<Vec<_>>();
+    random.shuffle(&mut letters);
+    let width = input.find('\n').unwrap_or(input.len());
+    letters
+        .chunks(width)
+        .flat_map(|row| row.iter().map(|letter| *letter as char).chain(['\n']))
+        .collect()
+}
+
+/// Writes `text` to `dir` as `YEAR/dayN.txt`, with `answers` in `YEAR/dayN.answers`, and returns
+/// the path written.
+pub fn write(
+    dir: &Path,
+    year: u16,
+    day: u8,
+    text: &str,
+    answers: [Answer; 2],
+) -> io::Result<PathBuf> {
+    let path = dir.join(format!("{year}/day{day}.txt"));
+    fs::create_dir_all(path.parent().unwrap())?;
+    fs::write(&path, text)?;
+    fs::write(
+        path.with_extension("answers"),
+        format!("{}\n{}\n", answers[0], answers[1]),
+    )?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The committed synthetic input of 2024 day `day`, standing in for a private one.
+    fn input(day: u8) -> String {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../synthetic");
+        fs::read_to_string(dir.join(format!("2024/day{day}.txt"))).unwrap()
+    }
+
+    fn answers(day: u8, input: &str) -> [Answer; 2] {
+        [1, 2].map(|part| registry::find(2024, day, part).unwrap().run(input))
+    }
+
+    #[test]
+    fn test_reproducible() {
+        let input = input(4);
+        let synthetic = synthesize(2024, 4, &input, 7).unwrap();
+        assert_eq!(synthesize(2024, 4, &input, 7).unwrap(), synthetic);
+        assert_ne!(synthesize(2024, 4, &input, 8).unwrap(), synthetic);
+
+        assert!(synthesize(2024, 4, "XMAS\n", 7).is_err());
+        assert!(synthesize(2024, 25, &input, 7).is_err());
+    }
+
+    #[test]
+    fn test_day1_keeps_repeats_and_matches() {
+        let shape = |input: &str| {
+            let numbers = input
+                .split_ascii_whitespace()
+                .map(|number| number.parse::<u32>().unwrap())
+                .collect::<Vec<_>>();
+            let (left, right): (Vec<_>, Vec<_>) =
+                numbers.chunks(2).map(|pair| (pair[0], pair[1])).unzip();
+            let mut repeats = right
+                .iter()
+                .map(|number| right.iter().filter(|other| *other == number).count())
+                .collect::<Vec<_>>();
+            repeats.sort_unstable();
+            let matches = left.iter().filter(|number| right.contains(number)).count();
+            (repeats, matches)
+        };
+        let input = input(1);
+        let synthetic = synthesize(2024, 1, &input, 1).unwrap();
+        assert_eq!(shape(&synthetic), shape(&input));
+        assert_ne!(answers(1, &synthetic), answers(1, &input));
+    }
+
+    #[test]
+    fn test_day2_draws_answers_from_seed() {
+        let input = input(2);
+        let synthetic = synthesize(2024, 2, &input, 1).unwrap();
+        let lengths = |input: &str| {
+            input
+                .lines()
+                .map(|report| report.split(' ').count())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(lengths(&synthetic), lengths(&input));
+
+        let count = input.lines().count() as u64;
+        let [safe, tolerated] =
+            answers(2, &synthetic).map(|answer| answer.to_string().parse::<u64>().unwrap());
+        assert!((count / 5..=2 * count / 5).contains(&safe), "{safe}");
+        assert!(
+            (count / 10..=count / 5).contains(&(tolerated - safe)),
+            "{tolerated}"
+        );
+
+        // Reports of other safeties in another order give the same answers.
+        let reversed = input
+            .lines()
+            .rev()
+            .map(|report| format!("{report}\n"))
+            .collect::<String>();
+        let other = synthesize(2024, 2, &reversed, 1).unwrap();
+        assert_eq!(answers(2, &other), answers(2, &synthetic));
+    }
+
+    #[test]
+    fn test_day2_impossible_reports() {
+        // No strictly monotonic report of these lengths fits in the range of their levels.
+        for input in [
+            "5 5\n",
+            "1 2 3 1 2 3 1 2\n",
+            "1 2 3 1 2 3 1 2\n1 2 3 1 2 3 1 2\n",
+        ] {
+            assert!(day2::is_valid(input));
+            assert!(synthesize(2024, 2, input, 1).is_err(), "{input}");
+        }
+    }
+
+    #[test]
+    fn test_day3_keeps_instructions() {
+        let shape = |input: &str| {
+            day3::instructions(input)
+                .map(|(start, instruction)| match instruction {
+                    day3::Instruction::Mul(a, b) => {
+                        (start, a.to_string().len(), b.to_string().len())
+                    }
+                    day3::Instruction::Do => (start, 0, 0),
+                    day3::Instruction::Dont => (start, 0, 1),
+                })
+                .collect::<Vec<_>>()
+        };
+        let input = input(3);
+        let synthetic = synthesize(2024, 3, &input, 1).unwrap();
+        assert_eq!(shape(&synthetic), shape(&input));
+        assert_eq!(
+            synthetic.match_indices('\n').collect::<Vec<_>>(),
+            input.match_indices('\n').collect::<Vec<_>>()
+        );
+        assert_ne!(answers(3, &synthetic), answers(3, &input));
+    }
+
+    #[test]
+    fn test_day4_keeps_letters() {
+        let letters = |input: &str| {
+            let mut letters = input.bytes().collect::<Vec<_>>();
+            letters.sort_unstable();
+            letters
+        };
+        let input = input(4);
+        let synthetic = synthesize(2024, 4, &input, 1).unwrap();
+        assert_eq!(letters(&synthetic), letters(&input));
+    }
+}
@@ -0,0 +1,289 @@
+//! Rebuilds, tests and times a day whenever its sources or inputs change, and shows how the
+//! answers and timings moved since the previous round.
+//!
+//! The watcher can't reload the library it was built with, so every round goes through `cargo`:
+//! `cargo test` on the day's tests, then `cargo run --release` of `run` with a history file the
+//! round's records are read back from.
+
+use crate::history::{self, Record};
+use std::{
+    collections::BTreeMap,
+    env,
+    ffi::OsString,
+    fs, io,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+/// What to rebuild, test and time.
+pub struct Watch<'a> {
+    pub root: &'a Path,
+    pub year: Option<u16>,
+    pub day: u8,
+    pub part: Option<u8>,
+    pub iterations: u32,
+    pub inputs: &'a Path,
+    pub interval: Duration,
+}
+
+/// The modification time of every file under the watched directories.
+type Snapshot = BTreeMap<PathBuf, SystemTime>;
+
+fn snapshot(dirs: &[PathBuf]) -> io::Result<Snapshot> {
+    fn visit(dir: &Path, snapshot: &mut Snapshot) -> io::Result<()> {
+        let entries = match fs::read_dir(dir) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(()),
+            Err(err) => return Err(err),
+        };
+        for entry in entries {
+            let entry = entry?;
+            let metadata = entry.metadata()?;
+            if metadata.is_dir() {
+                visit(&entry.path(), snapshot)?;
+            } else {
+                snapshot.insert(entry.path(), metadata.modified()?);
+            }
+        }
+        Ok(())
+    }
+
+    let mut snapshot = Snapshot::new();
+    for dir in dirs {
+        visit(dir, &mut snapshot)?;
+    }
+    Ok(snapshot)
+}
+
+/// Returns the files added, removed or modified between `before` and `after`.
+fn changes<'a>(before: &'a Snapshot, after: &'a Snapshot) -> Vec<&'a Path> {
+    let modified = after
+        .iter()
+        .filter(|(path, time)| before.get(*path) != Some(time))
+        .map(|(path, _)| path.as_path());
+    let removed = before
+        .keys()
+        .filter(|path| !after.contains_key(*path))
+        .map(PathBuf::as_path);
+    modified.chain(removed).collect()
+}
+
+/// Runs a round whenever a file under `src/` or the inputs changes, until interrupted.
+pub fn watch(watch: &Watch) -> io::Result<()> {
+    let dirs = [watch.root.join("src"), watch.root.join(watch.inputs)];
+    let mut before = snapshot(&dirs)?;
+    let mut previous = Vec::new();
+    println!(
+        "watching {}, stop with Ctrl-C",
+        dirs.each_ref()
+            .map(|dir| dir.display().to_string())
+            .join(" and ")
+    );
+    loop {
+        if let Some(records) = round(watch)? {
+            for line in diff(&previous, &records) {
+                println!("{line}");
+            }
+            previous = records;
+        }
+
+        let changed = loop {
+            thread::sleep(watch.interval);
+            let after = snapshot(&dirs)?;
+            let changed: Vec<_> = changes(&before, &after)
+                .into_iter()
+                .map(Path::to_path_buf)
+                .collect();
+            if !changed.is_empty() {
+                // Editors save in several steps, so wait for the files to settle.
+                thread::sleep(watch.interval);
+                before = snapshot(&dirs)?;
+                break changed;
+            }
+        };
+        let more = match changed.len() {
+            1 => String::new(),
+            n => format!(" and {} more", n - 1),
+        };
+        println!("\n{} changed{more}", changed[0].display());
+    }
+}
+
+/// Tests and times the day, and returns the records of the timing pass, or `None` if the build,
+/// the tests or the timing pass failed.
+fn round(watch: &Watch) -> io::Result<Option<Vec<Record>>> {
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| OsString::from("cargo"));
+    let filter = match watch.year {
+        Some(year) => format!("y{year}::day{}::", watch.day),
+        None => format!("::day{}::", watch.day),
+    };
+    let tests = Command::new(&cargo)
+        .current_dir(watch.root)
+        .args([
+            "test",
+            "-q",
+            "-p",
+            "aoc-2024-public",
+            "--lib",
+            "--",
+            &filter,
+        ])
+        .status()?;
+    if !tests.success() {
+        println!("tests failed");
+        return Ok(None);
+    }
+
+    let history = env::temp_dir().join(format!("aoc-watch-{}.jsonl", std::process::id()));
+    let _ = fs::remove_file(&history);
+    let mut run = Command::new(&cargo);
+    run.current_dir(watch.root)
+        .args([
+            "run",
+            "-q",
+            "--release",
+            "-p",
+            "aoc-2024-public-runner",
+            "--",
+        ])
+        .args(["run", "--day", &watch.day.to_string()])
+        .args(["--iterations", &watch.iterations.to_string()])
+        .arg("--inputs")
+        .arg(watch.inputs)
+        .arg("--history")
+        .arg(&history)
+        // The diff shows the answers and timings instead.
+        .stdout(Stdio::null());
+    if let Some(year) = watch.year {
+        run.args(["--year", &year.to_string()]);
+    }
+    if let Some(part) = watch.part {
+        run.args(["--part", &part.to_string()]);
+    }
+    let status = run.status()?;
+    let records = match history::load(&history) {
+        Ok(records) => records,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Vec::new(),
+        Err(err) => return Err(err),
+    };
+    let _ = fs::remove_file(&history);
+    if !status.success() || records.is_empty() {
+        println!("timing failed");
+        return Ok(None);
+    }
+    Ok(Some(records))
+}
+
+/// Returns one line per record of `current`, with its answer and median time and how they moved
+/// since the same implementation and input in `previous`.
+fn diff(previous: &[Record], current: &[Record]) -> Vec<String> {
+    current
+        .iter()
+        .map(|record| {
+            let before = previous.iter().find(|before| {
+                (before.year, before.day, before.part) == (record.year, record.day, record.part)
+                    && before.implementation == record.implementation
+                    && before.input == record.input
+            });
+            let answer = record.answer.as_deref().unwrap_or("");
+            let answer = match before.and_then(|before| before.answer.as_deref()) {
+                Some(was) if was != answer => format!("{answer} (was {was})"),
+                _ => answer.to_owned(),
+            };
+            let change = match before {
+                Some(before) => format!(
+                    "{:>+8.1}%",
+                    (record.median_ns as f64 / before.median_ns.max(1) as f64 - 1.0) * 100.0
+                ),
+                None => format!("{:>9}", "new"),
+            };
+            format!(
+                "{} day {} part {} {:<8} {:<16} {answer:<24} {:>10.2?} {change}",
+                record.year,
+                record.day,
+                record.part,
+                format!("{}:", record.implementation),
+                record.input,
+                Duration::from_nanos(record.median_ns),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(implementation: &str, answer: &str, median_ns: u64) -> Record {
+        Record {
+            timestamp: 0,
+            commit: "next".to_owned(),
+            cpu: "test".to_owned(),
+            year: 2024,
+            day: 4,
+            part: 1,
+            implementation: implementation.to_owned(),
+            input: "2024/day4.txt".to_owned(),
+            answer: Some(answer.to_owned()),
+            samples: 20,
+            median_ns,
+            p95_ns: median_ns,
+            mean_ns: median_ns as f64,
+            stddev_ns: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_diff() {
+        let previous = [record("simd", "412", 10_000), record("scalar", "412", 1000)];
+        let current = [
+            record("simd", "412", 9_000),
+            record("scalar", "411", 1100),
+            record("bits", "412", 500),
+        ];
+        assert_eq!(
+            diff(&previous, &current),
+            [
+                "2024 day 4 part 1 simd:    2024/day4.txt    412                          9.00µs    -10.0%",
+                "2024 day 4 part 1 scalar:  2024/day4.txt    411 (was 412)                1.10µs    +10.0%",
+                "2024 day 4 part 1 bits:    2024/day4.txt    412                        500.00ns       new",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_changes() {
+        let dir = env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+        fs::create_dir_all(dir.join("src/y2024")).unwrap();
+        fs::write(dir.join("src/y2024/day4.rs"), "").unwrap();
+        fs::write(dir.join("src/lib.rs"), "").unwrap();
+        let dirs = [dir.join("src"), dir.join("inputs")];
+
+        let before = snapshot(&dirs).unwrap();
+        assert_eq!(before.len(), 2);
+        assert!(changes(&before, &snapshot(&dirs).unwrap()).is_empty());
+
+        let file = fs::File::options()
+            .write(true)
+            .open(dir.join("src/y2024/day4.rs"))
+            .unwrap();
+        file.set_modified(SystemTime::now() + Duration::from_secs(1))
+            .unwrap();
+        fs::remove_file(dir.join("src/lib.rs")).unwrap();
+        fs::create_dir_all(dir.join("inputs/2024")).unwrap();
+        fs::write(dir.join("inputs/2024/day4.txt"), "XMAS\n").unwrap();
+        let after = snapshot(&dirs).unwrap();
+        assert_eq!(
+            changes(&before, &after),
+            [
+                dir.join("inputs/2024/day4.txt"),
+                dir.join("src/y2024/day4.rs"),
+                dir.join("src/lib.rs"),
+            ]
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
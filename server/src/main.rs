@@ -0,0 +1,147 @@
+//! Serves the registered solutions over HTTP, for tools that want answers without linking Rust.
+//!
+//! `POST /solve/{year}/{day}/{part}` with the input as body answers with
+//! `{"answer": .., "elapsed_ns": .., "implementation": ..}`, or `{"error": ..}` and a 4xx status.
+//! `/solve/{day}/{part}` still solves the days of 2024, for clients written before other years.
+//! `?implementation=NAME` picks another implementation than the default one. Inputs are
+//! normalized and checked with `dayN::is_valid` first, like in the other bindings.
+
+use aoc_2024_public::{normalize::normalize, registry};
+use clap::Parser;
+use serde_json::{json, Value};
+use std::{io::Read, panic, process::ExitCode, thread, time::Instant};
+use tiny_http::{Header, Method, Request, Response, Server};
+
+#[derive(Parser)]
+#[command(
+    name = "aoc-server",
+    about = "Serve the Advent of Code solutions over HTTP"
+)]
+struct Cli {
+    /// Address to listen on, where port 0 picks a free port.
+    #[arg(long, default_value = "127.0.0.1:8024")]
+    addr: String,
+    /// Largest accepted input, in bytes.
+    #[arg(long, default_value_t = 1 << 20)]
+    max_body: u64,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let server = match Server::http(&cli.addr) {
+        Ok(server) => server,
+        Err(err) => {
+            eprintln!("cannot listen on {}: {err}", cli.addr);
+            return ExitCode::FAILURE;
+        }
+    };
+    // Scripts and tests that ask for port 0 read the actual port from this line.
+    match server.server_addr().to_ip() {
+        Some(addr) => println!("listening on http://{addr}"),
+        None => println!("listening on {}", cli.addr),
+    }
+
+    for request in server.incoming_requests() {
+        thread::spawn(move || respond(request, cli.max_body));
+    }
+    ExitCode::SUCCESS
+}
+
+fn respond(mut request: Request, max_body: u64) {
+    let line = format!("{} {}", request.method(), request.url());
+    let (status, body) = handle(&mut request, max_body);
+    let response = Response::from_string(body.to_string())
+        .with_status_code(status)
+        .with_header(Header::from_bytes("Content-Type", "application/json").unwrap());
+    if let Err(err) = request.respond(response) {
+        eprintln!("cannot respond to {line}: {err}");
+    }
+}
+
+fn error(status: u16, message: impl Into<String>) -> (u16, Value) {
+    (status, json!({ "error": message.into() }))
+}
+
+/// Parses `{year}/{day}/{part}`, or `{day}/{part}` of 2024.
+fn route(route: &str) -> Option<(u16, u8, u8)> {
+    let segments: Vec<_> = route.split('/').collect();
+    let (year, day, part) = match segments[..] {
+        [year, day, part] => (year.parse().ok()?, day, part),
+        [day, part] => (2024, day, part),
+        _ => return None,
+    };
+    Some((year, day.parse().ok()?, part.parse().ok()?))
+}
+
+fn handle(request: &mut Request, max_body: u64) -> (u16, Value) {
+    let url = request.url().to_owned();
+    let (path, query) = url.split_once('?').unwrap_or((&url, ""));
+    let Some((year, day, part)) = path.strip_prefix("/solve/").and_then(route) else {
+        return error(
+            404,
+            format!("no route for {path}, use /solve/{{year}}/{{day}}/{{part}}"),
+        );
+    };
+    if *request.method() != Method::Post {
+        return error(405, "use POST with the input as body");
+    }
+
+    let Some(solution) = registry::find(year, day, part) else {
+        return error(404, format!("{year} day {day} part {part} is not solved"));
+    };
+    let implementation = match query
+        .split('&')
+        .find_map(|param| param.strip_prefix("implementation="))
+    {
+        None => &solution.implementations[0],
+        Some(name) => match solution.implementation(name) {
+            Some(implementation) => implementation,
+            None => return error(404, format!("{solution} has no implementation {name}")),
+        },
+    };
+
+    let too_large = || error(413, format!("the input is larger than {max_body} bytes"));
+    if request
+        .body_length()
+        .is_some_and(|len| len as u64 > max_body)
+    {
+        return too_large();
+    }
+    let mut body = Vec::new();
+    // Chunked bodies have no length up front, so never read more than one byte past the limit.
+    if let Err(err) = request
+        .as_reader()
+        .take(max_body + 1)
+        .read_to_end(&mut body)
+    {
+        return error(400, format!("cannot read the input: {err}"));
+    }
+    if body.len() as u64 > max_body {
+        return too_large();
+    }
+    let Ok(input) = String::from_utf8(body) else {
+        return error(400, "the input is not UTF-8");
+    };
+
+    let input = normalize(&input);
+    if !(solution.is_valid)(&input) {
+        return error(422, format!("input is not a valid {year} day {day} input"));
+    }
+    let start = Instant::now();
+    let Ok(answer) = panic::catch_unwind(|| (implementation.run)(&input)) else {
+        return error(500, format!("{solution} panicked"));
+    };
+    let elapsed = start.elapsed();
+    if answer.is_overflow() {
+        return error(422, "the answer does not fit in 64 bits");
+    }
+
+    (
+        200,
+        json!({
+            "answer": answer,
+            "elapsed_ns": elapsed.as_nanos() as u64,
+            "implementation": implementation.name,
+        }),
+    )
+}
@@ -0,0 +1,118 @@
+use serde_json::Value;
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::TcpStream,
+    process::{Child, Command, Stdio},
+};
+
+/// A server on a free port, killed when dropped.
+struct Server {
+    child: Child,
+    addr: String,
+}
+
+impl Server {
+    fn start() -> Self {
+        let mut child = Command::new(env!("CARGO_BIN_EXE_aoc-server"))
+            .args(["--addr", "127.0.0.1:0", "--max-body", "4096"])
+            .stdout(Stdio::piped())
+            .spawn()
+            .unwrap();
+        let mut line = String::new();
+        BufReader::new(child.stdout.take().unwrap())
+            .read_line(&mut line)
+            .unwrap();
+        let addr = line
+            .trim()
+            .strip_prefix("listening on http://")
+            .unwrap_or_else(|| panic!("unexpected first line {line:?}"))
+            .to_owned();
+        Self { child, addr }
+    }
+
+    fn request(&self, method: &str, path: &str, body: &[u8]) -> (u16, Value) {
+        let mut stream = TcpStream::connect(&self.addr).unwrap();
+        write!(
+            stream,
+            "{method} {path} HTTP/1.1\r\nHost: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+            self.addr,
+            body.len()
+        )
+        .unwrap();
+        // The server may answer before reading an oversized body and close the connection.
+        let _ = stream.write_all(body);
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+
+        let (head, body) = response.split_once("\r\n\r\n").unwrap();
+        let status = head.split(' ').nth(1).unwrap().parse().unwrap();
+        (status, serde_json::from_str(body).unwrap())
+    }
+
+    fn solve(&self, path: &str, body: &str) -> (u16, Value) {
+        self.request("POST", path, body.as_bytes())
+    }
+}
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+const DAY3_EXAMPLE: &str =
+    "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))\n";
+
+#[test]
+fn test_solve() {
+    let server = Server::start();
+    let (status, body) = server.solve("/solve/2024/3/1", DAY3_EXAMPLE);
+    assert_eq!(status, 200);
+    assert_eq!(body["answer"], 161);
+    assert_eq!(body["implementation"], "memmem");
+    assert!(body["elapsed_ns"].is_u64());
+
+    let (status, body) = server.solve("/solve/2024/3/1?implementation=scalar", DAY3_EXAMPLE);
+    assert_eq!(status, 200);
+    assert_eq!(body["answer"], 161);
+    assert_eq!(body["implementation"], "scalar");
+
+    // Without a year, the route solves the days of 2024.
+    let (status, body) = server.solve("/solve/3/2", DAY3_EXAMPLE);
+    assert_eq!(status, 200);
+    assert_eq!(body["answer"], 161);
+
+    // Inputs are normalized like in the other bindings.
+    let (status, body) = server.solve("/solve/2/1", "\u{feff}7 6 4 2 1\r\n1 3 6 7 9\r\n");
+    assert_eq!(status, 200);
+    assert_eq!(body["answer"], 2);
+}
+
+#[test]
+fn test_errors() {
+    let server = Server::start();
+    assert_eq!(server.request("GET", "/solve/3/1", b"").0, 405);
+    assert_eq!(server.solve("/solve/25/1", DAY3_EXAMPLE).0, 404);
+    assert_eq!(server.solve("/solve/2015/3/1", DAY3_EXAMPLE).0, 404);
+    assert_eq!(server.solve("/solve/2024/3/1/1", DAY3_EXAMPLE).0, 404);
+    assert_eq!(server.solve("/solve/3", DAY3_EXAMPLE).0, 404);
+    assert_eq!(
+        server
+            .solve("/solve/3/1?implementation=regex", DAY3_EXAMPLE)
+            .0,
+        404
+    );
+
+    let (status, body) = server.solve("/solve/4/1", "XMAS\n");
+    assert_eq!(status, 422);
+    assert_eq!(body["error"], "input is not a valid 2024 day 4 input");
+
+    assert_eq!(
+        server.request("POST", "/solve/3/1", b"mul(1,2)\xff\n").0,
+        400
+    );
+    assert_eq!(server.solve("/solve/3/1", &"x".repeat(5000)).0, 413);
+    // The limit is inclusive.
+    assert_eq!(server.solve("/solve/3/1", &"x".repeat(4096)).0, 200);
+}
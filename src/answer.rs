@@ -0,0 +1,226 @@
+//! The answer type every solver returns.
+
+use core::{
+    fmt,
+    iter::Sum,
+    ops::{Add, Mul, Sub},
+};
+
+/// A puzzle answer: any value that fits in a `u64` or an `i64`, or an overflow.
+///
+/// Arithmetic on answers is checked, and once a result doesn't fit in 64 bits it
+/// stays an overflow, so a solver can accumulate into an `Answer` and report the
+/// overflow instead of a wrapped value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Answer(Repr);
+
+// Non-negative values are always `Unsigned`, so derived equality compares numbers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Repr {
+    Unsigned(u64),
+    Negative(i64),
+    Overflow,
+}
+
+impl Answer {
+    pub const OVERFLOW: Self = Self(Repr::Overflow);
+
+    /// `Answer::from` for `const fn`s, which cannot call trait methods.
+    pub const fn from_u64(value: u64) -> Self {
+        Self(Repr::Unsigned(value))
+    }
+
+    pub const fn is_overflow(self) -> bool {
+        matches!(self.0, Repr::Overflow)
+    }
+
+    pub const fn to_u64(self) -> Option<u64> {
+        match self.0 {
+            Repr::Unsigned(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub const fn to_i64(self) -> Option<i64> {
+        match self.0 {
+            Repr::Unsigned(value) if value <= i64::MAX as u64 => Some(value as i64),
+            Repr::Negative(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub const fn to_i128(self) -> Option<i128> {
+        match self.0 {
+            Repr::Unsigned(value) => Some(value as i128),
+            Repr::Negative(value) => Some(value as i128),
+            Repr::Overflow => None,
+        }
+    }
+
+    #[inline(always)]
+    fn checked(self, rhs: Self, op: fn(i128, i128) -> Option<i128>) -> Self {
+        match (self.to_i128(), rhs.to_i128()) {
+            (Some(lhs), Some(rhs)) => op(lhs, rhs).map_or(Self::OVERFLOW, Self::from),
+            _ => Self::OVERFLOW,
+        }
+    }
+}
+
+macro_rules! impl_from_unsigned {
+    ($($ty:ty),*) => {$(
+        impl From<$ty> for Answer {
+            #[inline(always)]
+            fn from(value: $ty) -> Self {
+                Self(Repr::Unsigned(value as u64))
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_from_signed {
+    ($($ty:ty),*) => {$(
+        impl From<$ty> for Answer {
+            #[inline(always)]
+            fn from(value: $ty) -> Self {
+                match value {
+                    0.. => Self(Repr::Unsigned(value as u64)),
+                    _ => Self(Repr::Negative(value as i64)),
+                }
+            }
+        }
+    )*};
+}
+
+impl_from_unsigned!(u8, u16, u32, u64, usize);
+impl_from_signed!(i8, i16, i32, i64, isize);
+
+/// Values outside the `i64` and `u64` ranges become [`Answer::OVERFLOW`].
+impl From<i128> for Answer {
+    fn from(value: i128) -> Self {
+        match (u64::try_from(value), i64::try_from(value)) {
+            (Ok(value), _) => Self(Repr::Unsigned(value)),
+            (_, Ok(value)) => Self(Repr::Negative(value)),
+            _ => Self::OVERFLOW,
+        }
+    }
+}
+
+impl Add for Answer {
+    type Output = Self;
+
+    #[inline(always)]
+    fn add(self, rhs: Self) -> Self::Output {
+        match (self.0, rhs.0) {
+            // Solvers mostly accumulate non-negative values, which skip the `i128` round trip.
+            (Repr::Unsigned(lhs), Repr::Unsigned(rhs)) => {
+                lhs.checked_add(rhs).map_or(Self::OVERFLOW, Self::from)
+            }
+            _ => self.checked(rhs, i128::checked_add),
+        }
+    }
+}
+
+impl Sub for Answer {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.checked(rhs, i128::checked_sub)
+    }
+}
+
+impl Mul for Answer {
+    type Output = Self;
+
+    #[inline(always)]
+    fn mul(self, rhs: Self) -> Self::Output {
+        match (self.0, rhs.0) {
+            // Solvers mostly accumulate non-negative values, which skip the `i128` round trip.
+            (Repr::Unsigned(lhs), Repr::Unsigned(rhs)) => {
+                lhs.checked_mul(rhs).map_or(Self::OVERFLOW, Self::from)
+            }
+            _ => self.checked(rhs, i128::checked_mul),
+        }
+    }
+}
+
+impl Sum for Answer {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::from(0u64), Add::add)
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Repr::Unsigned(value) => value.fmt(f),
+            Repr::Negative(value) => value.fmt(f),
+            Repr::Overflow => f.pad("overflow"),
+        }
+    }
+}
+
+/// Serializes as a plain integer, or fails for an overflow.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Answer {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.0 {
+            Repr::Unsigned(value) => serializer.serialize_u64(value),
+            Repr::Negative(value) => serializer.serialize_i64(value),
+            Repr::Overflow => Err(serde::ser::Error::custom(
+                "the answer does not fit in 64 bits",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from() {
+        assert_eq!(Answer::from(5i32), Answer::from(5usize));
+        assert_eq!(Answer::from(-5i8).to_i64(), Some(-5));
+        assert_eq!(Answer::from(u64::MAX).to_i64(), None);
+        assert_eq!(Answer::from(u64::MAX as i128 + 1), Answer::OVERFLOW);
+        assert_eq!(
+            Answer::from(i64::MIN as i128).to_i128(),
+            Some(i64::MIN as i128)
+        );
+    }
+
+    #[test]
+    fn test_overflow() {
+        let max = Answer::from(u64::MAX);
+        assert!((max + Answer::from(1)).is_overflow());
+        assert!((max + Answer::from(1) - Answer::from(1)).is_overflow());
+        assert_eq!(max - Answer::from(u64::MAX), Answer::from(0));
+        assert_eq!(Answer::from(3) - Answer::from(5), Answer::from(-2));
+        assert!((Answer::from(i64::MIN) * Answer::from(2)).is_overflow());
+        assert_eq!(
+            [999u32 * 999; 5000]
+                .into_iter()
+                .map(Answer::from)
+                .sum::<Answer>(),
+            Answer::from(4_990_005_000u64)
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", Answer::from(-42)), "-42");
+        assert_eq!(format!("{:>5}", Answer::from(42)), "   42");
+        assert_eq!(format!("{:<9}|", Answer::OVERFLOW), "overflow |");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serialize() {
+        assert_eq!(
+            serde_json::to_string(&Answer::from(u64::MAX)).unwrap(),
+            "18446744073709551615"
+        );
+        assert_eq!(serde_json::to_string(&Answer::from(-7)).unwrap(), "-7");
+        assert!(serde_json::to_string(&Answer::OVERFLOW).is_err());
+    }
+}
@@ -0,0 +1,352 @@
+//! Bit-parallel grids: every row becomes a bitmask per character, so a pattern search is a few
+//! shifts and `&`s per row instead of a byte-by-byte scan.
+
+use core::ops::{BitAnd, BitOr, BitXor, Not, Shl, Shr};
+#[cfg(feature = "nightly")]
+use core::simd::prelude::*;
+
+/// A bitmask over the columns of a row, `WORDS * 64` bits wide. Bit `i` stands for column `i`.
+///
+/// Shifts move bits towards higher (`<<`) or lower (`>>`) columns. Nothing keeps bits past the
+/// width of the grid clear, so mask them off (see [`RowMask::first`]) before counting if shifts or
+/// [`Not`] may have set them.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct RowMask<const WORDS: usize> {
+    words: [u64; WORDS],
+}
+
+impl<const WORDS: usize> RowMask<WORDS> {
+    pub const ZERO: Self = Self { words: [0; WORDS] };
+
+    pub const fn new(words: [u64; WORDS]) -> Self {
+        Self { words }
+    }
+
+    /// Returns a mask with the lowest `n` bits set.
+    pub const fn first(n: usize) -> Self {
+        let mut words = [0; WORDS];
+        let mut i = 0;
+        while i < WORDS {
+            words[i] = match n.saturating_sub(i * 64) {
+                0 => 0,
+                bits @ 1..64 => (1 << bits) - 1,
+                _ => u64::MAX,
+            };
+            i += 1;
+        }
+        Self { words }
+    }
+
+    pub const fn words(&self) -> [u64; WORDS] {
+        self.words
+    }
+
+    #[inline(always)]
+    pub fn get(&self, bit: usize) -> bool {
+        self.words[bit / 64] >> (bit % 64) & 1 == 1
+    }
+
+    #[inline(always)]
+    pub fn set(&mut self, bit: usize) {
+        self.words[bit / 64] |= 1 << (bit % 64);
+    }
+
+    #[inline(always)]
+    pub fn clear(&mut self, bit: usize) {
+        self.words[bit / 64] &= !(1 << (bit % 64));
+    }
+
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|word| *word == 0)
+    }
+
+    #[inline(always)]
+    pub fn count_ones(&self) -> u32 {
+        self.words.iter().map(|word| word.count_ones()).sum()
+    }
+
+    /// Iterates over the set bits, from the lowest.
+    pub fn iter_ones(&self) -> impl Iterator<Item = usize> {
+        self.words
+            .into_iter()
+            .enumerate()
+            .flat_map(|(i, mut word)| {
+                core::iter::from_fn(move || {
+                    let bit = word.trailing_zeros();
+                    (word != 0).then(|| {
+                        word &= word - 1;
+                        i * 64 + bit as usize
+                    })
+                })
+            })
+    }
+}
+
+impl<const WORDS: usize> Shl<usize> for RowMask<WORDS> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn shl(self, rhs: usize) -> Self::Output {
+        let (skip, bits) = (rhs / 64, rhs % 64);
+        let word = |i: usize, skip: usize| i.checked_sub(skip).map_or(0, |i| self.words[i]);
+        Self {
+            words: core::array::from_fn(|i| match bits {
+                0 => word(i, skip),
+                _ => word(i, skip) << bits | word(i, skip + 1) >> (64 - bits),
+            }),
+        }
+    }
+}
+
+impl<const WORDS: usize> Shr<usize> for RowMask<WORDS> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn shr(self, rhs: usize) -> Self::Output {
+        let (skip, bits) = (rhs / 64, rhs % 64);
+        let word = |i: usize| self.words.get(i).copied().unwrap_or(0);
+        Self {
+            words: core::array::from_fn(|i| match bits {
+                0 => word(i + skip),
+                _ => word(i + skip) >> bits | word(i + skip + 1) << (64 - bits),
+            }),
+        }
+    }
+}
+
+macro_rules! impl_bit_op {
+    ($trait:ident, $fn:ident, $op:tt) => {
+        impl<const WORDS: usize> $trait for RowMask<WORDS> {
+            type Output = Self;
+
+            #[inline(always)]
+            fn $fn(self, rhs: Self) -> Self::Output {
+                Self {
+                    words: core::array::from_fn(|i| self.words[i] $op rhs.words[i]),
+                }
+            }
+        }
+    };
+}
+
+impl_bit_op!(BitAnd, bitand, &);
+impl_bit_op!(BitOr, bitor, |);
+impl_bit_op!(BitXor, bitxor, ^);
+
+impl<const WORDS: usize> Not for RowMask<WORDS> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn not(self) -> Self::Output {
+        Self {
+            words: self.words.map(|word| !word),
+        }
+    }
+}
+
+/// The bytes of a row, loaded into SIMD registers so that [`Row::eq`] is a few compares.
+#[cfg(feature = "nightly")]
+#[derive(Debug, Copy, Clone)]
+pub struct Row<const WORDS: usize> {
+    chunks: [u8x64; WORDS],
+}
+
+#[cfg(feature = "nightly")]
+impl<const WORDS: usize> Row<WORDS> {
+    /// Loads `row`, which must not be longer than `WORDS * 64` bytes.
+    #[inline(always)]
+    pub fn new(row: &[u8]) -> Self {
+        assert!(row.len() <= WORDS * 64);
+
+        // IMPORTANT: Never use load_or_default, even for the partial last chunk, since
+        // it'll include lots of other instructions and slow down the overall solution.
+        let mut chunks = [u8x64::splat(0); WORDS];
+        for (i, chunk) in chunks.iter_mut().enumerate() {
+            if row.len() >= i * 64 + 64 {
+                *chunk = u8x64::from_slice(&row[i * 64..]);
+            } else if row.len() > i * 64 {
+                let mut bytes = [0; 64];
+                bytes[..row.len() - i * 64].copy_from_slice(&row[i * 64..]);
+                *chunk = u8x64::from_array(bytes);
+            }
+        }
+
+        Self { chunks }
+    }
+
+    /// Returns the columns holding `byte`.
+    #[inline(always)]
+    pub fn eq(&self, byte: u8) -> RowMask<WORDS> {
+        let splat = Simd::splat(byte);
+        let mut words = [0; WORDS];
+        for (word, chunk) in words.iter_mut().zip(&self.chunks) {
+            *word = chunk.simd_eq(splat).to_bitmask();
+        }
+        RowMask::new(words)
+    }
+}
+
+/// The bytes of a row, padded with zeros to `WORDS * 64` bytes.
+#[cfg(not(feature = "nightly"))]
+#[derive(Debug, Copy, Clone)]
+pub struct Row<const WORDS: usize> {
+    chunks: [[u8; 64]; WORDS],
+}
+
+#[cfg(not(feature = "nightly"))]
+impl<const WORDS: usize> Row<WORDS> {
+    /// Loads `row`, which must not be longer than `WORDS * 64` bytes.
+    #[inline(always)]
+    pub fn new(row: &[u8]) -> Self {
+        assert!(row.len() <= WORDS * 64);
+
+        let mut chunks = [[0; 64]; WORDS];
+        for (chunk, bytes) in chunks.iter_mut().zip(row.chunks(64)) {
+            chunk[..bytes.len()].copy_from_slice(bytes);
+        }
+
+        Self { chunks }
+    }
+
+    /// Returns the columns holding `byte`. This scalar fallback for the `u8x64`
+    /// comparison is still vectorized by LLVM on most targets.
+    #[inline(always)]
+    pub fn eq(&self, byte: u8) -> RowMask<WORDS> {
+        RowMask::new(self.chunks.map(|chunk| {
+            chunk
+                .iter()
+                .enumerate()
+                .fold(0, |mask, (i, b)| mask | ((*b == byte) as u64) << i)
+        }))
+    }
+}
+
+/// A rectangular grid of newline-delimited rows, at most `WORDS * 64` columns wide.
+#[derive(Debug, Copy, Clone)]
+pub struct Grid<'a, const WORDS: usize> {
+    bytes: &'a [u8],
+    width: usize,
+    height: usize,
+}
+
+impl<'a, const WORDS: usize> Grid<'a, WORDS> {
+    /// Returns `None` unless `input` has rows of equal, non-zero width that fit in a
+    /// [`RowMask<WORDS>`], separated by `\n` with an optional final newline.
+    pub fn new(input: &'a str) -> Option<Self> {
+        let bytes = input.as_bytes();
+        let trimmed = bytes.strip_suffix(b"\n").unwrap_or(bytes);
+        let width = memchr::memchr(b'\n', trimmed).unwrap_or(trimmed.len());
+        if width == 0 || width > WORDS * 64 || !(trimmed.len() + 1).is_multiple_of(width + 1) {
+            return None;
+        }
+
+        let height = (trimmed.len() + 1) / (width + 1);
+        let rows_end_in_newline = (1..height).all(|y| trimmed[y * (width + 1) - 1] == b'\n');
+        let rows_have_no_newline = memchr::memchr_iter(b'\n', trimmed).count() == height - 1;
+        (rows_end_in_newline && rows_have_no_newline).then_some(Self {
+            bytes,
+            width,
+            height,
+        })
+    }
+
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the bytes of row `y`, without its newline.
+    #[inline(always)]
+    pub fn bytes(&self, y: usize) -> &'a [u8] {
+        &self.bytes[y * (self.width + 1)..][..self.width]
+    }
+
+    #[inline(always)]
+    pub fn get(&self, x: usize, y: usize) -> Option<u8> {
+        (x < self.width && y < self.height).then(|| self.bytes(y)[x])
+    }
+
+    #[inline(always)]
+    pub fn row(&self, y: usize) -> Row<WORDS> {
+        Row::new(self.bytes(y))
+    }
+
+    /// Returns the columns of row `y` holding `byte`.
+    #[inline(always)]
+    pub fn mask(&self, y: usize, byte: u8) -> RowMask<WORDS> {
+        self.row(y).eq(byte)
+    }
+
+    /// Returns the mask of `byte` for every row, from the top.
+    pub fn masks(&self, byte: u8) -> impl Iterator<Item = RowMask<WORDS>> + '_ {
+        (0..self.height).map(move |y| self.mask(y, byte))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shifts() {
+        let mask = RowMask::<3>::new([1 << 63 | 1, 1 << 63, 0]);
+        assert_eq!(mask << 1, RowMask::new([2, 1, 1]));
+        assert_eq!(mask << 65, RowMask::new([0, 2, 1]));
+        assert_eq!(mask >> 1, RowMask::new([1 << 62, 1 << 62, 0]));
+        assert_eq!(mask >> 64, RowMask::new([1 << 63, 0, 0]));
+        assert_eq!(mask << 0, mask);
+        assert_eq!(mask >> 192, RowMask::ZERO);
+    }
+
+    #[test]
+    fn test_bit_ops() {
+        let a = RowMask::<2>::new([0b1100, 1]);
+        let b = RowMask::<2>::new([0b1010, 3]);
+        assert_eq!(a & b, RowMask::new([0b1000, 1]));
+        assert_eq!(a | b, RowMask::new([0b1110, 3]));
+        assert_eq!(a ^ b, RowMask::new([0b0110, 2]));
+        assert_eq!(!a & RowMask::first(4), RowMask::new([0b0011, 0]));
+        assert_eq!(
+            RowMask::<3>::first(70),
+            RowMask::new([u64::MAX, 0b111111, 0])
+        );
+    }
+
+    #[test]
+    fn test_bits() {
+        let mut mask = RowMask::<2>::ZERO;
+        assert!(mask.is_empty());
+        for bit in [0, 63, 64, 100] {
+            mask.set(bit);
+        }
+        mask.clear(63);
+        assert!(mask.get(100) && !mask.get(63));
+        assert_eq!(mask.count_ones(), 3);
+        assert!(mask.iter_ones().eq([0, 64, 100]));
+    }
+
+    #[test]
+    fn test_grid() {
+        let grid = Grid::<1>::new("#..\n.#.\n..#\n").unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 3));
+        assert_eq!(grid.get(1, 1), Some(b'#'));
+        assert_eq!(grid.get(3, 0), None);
+        assert!(grid
+            .masks(b'#')
+            .map(|mask| mask.words()[0])
+            .eq([0b001, 0b010, 0b100]));
+
+        let wide = "x".repeat(130);
+        let grid = Grid::<3>::new(&wide).unwrap();
+        assert_eq!(grid.mask(0, b'x'), RowMask::first(130));
+
+        assert!(Grid::<1>::new("").is_none());
+        assert!(Grid::<1>::new("ab\nc\n").is_none());
+        assert!(Grid::<1>::new("ab\n\ncd").is_none());
+        assert!(Grid::<1>::new(&wide).is_none());
+    }
+}
@@ -0,0 +1,132 @@
+//! The puzzle inputs of every day and their known answers, for tools that check or time solutions
+//! on more than one account's input.
+//!
+//! The inputs of day `N` of year `Y` are `Y/dayN.txt` and every `Y/dayN/*.txt` in the inputs
+//! directory, such as `2024/day1.txt` and `2024/day1/alice.txt`. The answers
+//! of `NAME.txt` are the first two lines of `NAME.answers` next to it, one per part, where an empty
+//! line or a `?` marks an answer that is not known yet. Every input is [`normalize`]d on load.
+
+use crate::{normalize::normalize, Answer};
+use std::{fs, io, path::Path};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    /// The path relative to the inputs directory, such as `2024/day1.txt` or `2024/day1/alice.txt`.
+    pub name: String,
+    pub text: String,
+    /// The answers of part 1 and part 2, where known.
+    pub answers: [Option<Answer>; 2],
+}
+
+impl Input {
+    pub fn answer(&self, part: u8) -> Option<Answer> {
+        self.answers
+            .get(usize::from(part).wrapping_sub(1))
+            .copied()
+            .flatten()
+    }
+}
+
+/// Returns the inputs of `day` of `year` in `dir`, `Y/dayN.txt` first and then `Y/dayN/*.txt` by
+/// name.
+pub fn load(dir: &Path, year: u16, day: u8) -> io::Result<Vec<Input>> {
+    let day_dir = Path::new(&year.to_string()).join(format!("day{day}"));
+    let mut paths = vec![day_dir.with_extension("txt")];
+    match fs::read_dir(dir.join(&day_dir)) {
+        Ok(entries) => {
+            let mut names = Vec::new();
+            for entry in entries {
+                let path = entry?.path();
+                if path.extension().is_some_and(|extension| extension == "txt") {
+                    names.push(day_dir.join(path.file_name().unwrap()));
+                }
+            }
+            names.sort();
+            paths.extend(names);
+        }
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+        Err(err) => return Err(err),
+    }
+
+    let mut inputs = Vec::new();
+    for path in paths {
+        let text = match fs::read_to_string(dir.join(&path)) {
+            Ok(text) => normalize(&text).into_owned(),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => continue,
+            Err(err) => return Err(err),
+        };
+        let name = path.to_string_lossy().replace('\\', "/");
+        let answers = match fs::read_to_string(dir.join(path.with_extension("answers"))) {
+            Ok(answers) => parse_answers(&answers).map_err(|err| {
+                io::Error::new(io::ErrorKind::InvalidData, format!("{name}: {err}"))
+            })?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => [None; 2],
+            Err(err) => return Err(err),
+        };
+        inputs.push(Input {
+            name,
+            text,
+            answers,
+        });
+    }
+    Ok(inputs)
+}
+
+fn parse_answers(text: &str) -> Result<[Option<Answer>; 2], String> {
+    let mut answers = [None; 2];
+    for (answer, line) in answers.iter_mut().zip(text.lines()) {
+        let line = line.trim();
+        if line.is_empty() || line == "?" {
+            continue;
+        }
+        let value = line
+            .parse::<i128>()
+            .map_err(|err| format!("cannot parse the answer `{line}`: {err}"))?;
+        *answer = Some(Answer::from(value));
+    }
+    Ok(answers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load() {
+        let dir = std::env::temp_dir().join(format!("aoc-inputs-{}", std::process::id()));
+        fs::create_dir_all(dir.join("2015/day7")).unwrap();
+        fs::write(dir.join("2015/day7.txt"), "main\r\n").unwrap();
+        fs::write(dir.join("2015/day7/bob.txt"), "bob\n").unwrap();
+        fs::write(dir.join("2015/day7/bob.answers"), "?\n-12\n").unwrap();
+        fs::write(dir.join("2015/day7/alice.txt"), "alice\n").unwrap();
+        fs::write(dir.join("2015/day7/alice.answers"), "4990005000\n").unwrap();
+        fs::write(dir.join("2015/day7/notes.md"), "ignored\n").unwrap();
+
+        let inputs = load(&dir, 2015, 7).unwrap();
+        let names = inputs
+            .iter()
+            .map(|input| input.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            ["2015/day7.txt", "2015/day7/alice.txt", "2015/day7/bob.txt"]
+        );
+        assert_eq!(inputs[0].text, "main\n");
+        assert_eq!(inputs[0].answers, [None, None]);
+        assert_eq!(inputs[1].answer(1), Some(Answer::from(4_990_005_000u64)));
+        assert_eq!(inputs[1].answer(2), None);
+        assert_eq!(inputs[2].answers, [None, Some(Answer::from(-12))]);
+        assert_eq!(inputs[2].answer(3), None);
+
+        assert!(load(&dir, 2015, 8).unwrap().is_empty());
+        assert!(load(&dir, 2016, 7).unwrap().is_empty());
+
+        fs::write(dir.join("2015/day7/bob.answers"), "twelve\n").unwrap();
+        assert_eq!(
+            load(&dir, 2015, 7).unwrap_err().kind(),
+            io::ErrorKind::InvalidData
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
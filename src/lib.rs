@@ -1,16 +1,25 @@
-// #![no_std]
-#![feature(iter_array_chunks)]
-#![feature(const_trait_impl)]
-#![feature(const_for)]
-#![feature(decl_macro)]
-#![feature(test)]
-#![feature(portable_simd)]
-#![feature(target_feature_11)]
+#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(feature = "nightly", feature(const_trait_impl))]
+#![cfg_attr(all(feature = "std", feature = "nightly"), feature(test))]
+#![cfg_attr(feature = "nightly", feature(portable_simd))]
 
+#[cfg(all(feature = "std", feature = "nightly"))]
 extern crate test;
 
-#[allow(unused, non_snake_case)]
-pub mod day1;
-pub mod day2;
-pub mod day3;
-pub mod day4;
+#[macro_use]
+mod macros;
+
+pub mod answer;
+pub mod grid;
+#[cfg(feature = "std")]
+pub mod inputs;
+#[cfg(feature = "std")]
+pub mod normalize;
+pub mod parse;
+#[cfg(feature = "std")]
+pub mod registry;
+#[cfg(feature = "std")]
+pub mod trace;
+pub mod y2024;
+
+pub use answer::Answer;
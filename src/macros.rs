@@ -0,0 +1,156 @@
+//! The skeleton every day shares, so a day module only holds what is specific to its puzzle.
+
+/// Defines a safe `fn` returning an [`Answer`](crate::Answer) whose body is compiled with the
+/// x86-64-v3 target features, by moving it into an inner `unsafe fn` that enables them.
+macro_rules! target_feature_fn {
+    (
+        $(#[$attr:meta])*
+        $vis:vis fn $name:ident($($arg:ident: $ty:ty),* $(,)?) -> $ret:ty $body:block
+    ) => {
+        $(#[$attr])*
+        $vis fn $name($($arg: $ty),*) -> crate::Answer {
+            #[cfg_attr(
+                target_arch = "x86_64",
+                target_feature(enable = "avx2,bmi1,bmi2,cmpxchg16b,lzcnt,movbe,popcnt")
+            )]
+            unsafe fn inner($($arg: $ty),*) -> $ret $body
+
+            unsafe { inner($($arg),*) }.into()
+        }
+    };
+}
+
+/// Declares a day from the implementations of each part, the default one first, and its examples
+/// with their answers. Generates:
+///
+/// - `try_part1` and `try_part2`, which run `part1` and `part2` on inputs `is_valid` accepts,
+/// - `SOLUTIONS`, the day's entries in [`registry::SOLUTIONS`](crate::registry::SOLUTIONS), with
+///   `parse` and `solve_part*` as their phases, which the runner, the server and the benches use,
+/// - an `examples` test module checking every example against every implementation, against the
+///   `const_solvers` if given, and against the trace if the day has one. The `small_examples`,
+///   such as the puzzle's own when the fast paths only take full-size inputs, must not be
+///   `is_valid` and skip the implementations.
+///
+/// Without examples, it generates an ignored test instead, as a reminder to add them.
+macro_rules! day {
+    (
+        year: $year:literal,
+        day: $day:literal,
+        part1: [$($(#[$attr1:meta])* $name1:literal => $run1:ident),+ $(,)?],
+        part2: [$($(#[$attr2:meta])* $name2:literal => $run2:ident),+ $(,)?],
+        $(const_solvers: [$const1:ident, $const2:ident],)?
+        examples: [$($example:expr => ($answer1:expr, $answer2:expr)),* $(,)?]
+        $(, small_examples: [$($small:expr => ($small1:expr, $small2:expr)),* $(,)?])? $(,)?
+    ) => {
+        pub fn try_part1(input: &str) -> Option<crate::Answer> {
+            is_valid(input).then(|| part1(input))
+        }
+
+        pub fn try_part2(input: &str) -> Option<crate::Answer> {
+            is_valid(input).then(|| part2(input))
+        }
+
+        #[cfg(feature = "std")]
+        pub(crate) const SOLUTIONS: [crate::registry::Solution; 2] = [
+            day!(@solution $year, $day, 1, solve_part1, [$($(#[$attr1])* $name1 => $run1),+]),
+            day!(@solution $year, $day, 2, solve_part2, [$($(#[$attr2])* $name2 => $run2),+]),
+        ];
+
+        day!(
+            @examples $year, $day, [$($const1, $const2)?],
+            [$($example => ($answer1, $answer2)),*],
+            [$($($small => ($small1, $small2)),*)?]
+        );
+    };
+
+    (@solution $year:literal, $day:literal, $part:literal, $solve:ident, [$($(#[$attr:meta])* $name:literal => $run:ident),+]) => {
+        crate::registry::Solution {
+            year: $year,
+            day: $day,
+            part: $part,
+            implementations: &[$(
+                $(#[$attr])*
+                crate::registry::Implementation {
+                    name: $name,
+                    run: $run,
+                },
+            )+],
+            is_valid,
+            parse: |input| Box::new(parse(input)),
+            solve: |parsed| {
+                let parsed = crate::registry::downcast(parsed, parse);
+                $solve(parsed)
+            },
+        }
+    };
+
+    (@examples $year:literal, $day:literal, [$($const1:ident, $const2:ident)?], [], []) => {
+        #[cfg(all(test, feature = "std"))]
+        mod examples {
+            #[test]
+            #[ignore = "add the example from the puzzle"]
+            fn test_examples() {}
+        }
+    };
+
+    (
+        @examples $year:literal, $day:literal, [$($const1:ident, $const2:ident)?],
+        [$($example:expr => ($answer1:expr, $answer2:expr)),*],
+        [$($small:expr => ($small1:expr, $small2:expr)),*]
+    ) => {
+        #[cfg(all(test, feature = "std"))]
+        mod examples {
+            use super::*;
+            use crate::Answer;
+
+            /// Checks `example` against the trace and the const solvers, and against every
+            /// implementation unless it is `small`.
+            fn check(name: &str, example: &str, answers: [Answer; 2], small: bool) {
+                for (solution, expected) in SOLUTIONS.iter().zip(answers) {
+                    let part = solution.part;
+                    if small {
+                        assert!(
+                            !(solution.is_valid)(example),
+                            "{name} part {part} is valid, move it to the examples"
+                        );
+                    } else {
+                        assert!(
+                            (solution.is_valid)(example),
+                            "{name} part {part} is not valid, move it to the small examples"
+                        );
+                        for implementation in solution.implementations {
+                            assert_eq!(
+                                (implementation.run)(example),
+                                expected,
+                                "{name} part {part} {}",
+                                implementation.name
+                            );
+                        }
+                    }
+                    if let Some(steps) = crate::trace::trace($year, $day, part, example) {
+                        let total = steps.last().map_or(Answer::from(0), |step| step.total);
+                        assert_eq!(total, expected, "{name} part {part} trace");
+                    }
+                }
+                $(
+                    assert_eq!($const1(example), answers[0], "{name} part 1 const");
+                    assert_eq!($const2(example), answers[1], "{name} part 2 const");
+                )?
+            }
+
+            #[test]
+            fn test_examples() {
+                let examples: &[(&str, [Answer; 2])] =
+                    &[$(($example, [Answer::from($answer1), Answer::from($answer2)])),*];
+                for (i, (example, answers)) in examples.iter().enumerate() {
+                    check(&format!("example {i}"), example, *answers, false);
+                }
+                let small: &[(&str, [Answer; 2])] =
+                    &[$(($small, [Answer::from($small1), Answer::from($small2)])),*];
+                for (i, (example, answers)) in small.iter().enumerate() {
+                    check(&format!("small example {i}"), example, *answers, true);
+                }
+            }
+        }
+    };
+}
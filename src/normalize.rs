@@ -0,0 +1,78 @@
+//! Brings inputs saved on Windows or copied from a browser into the form the solvers expect.
+
+use std::borrow::Cow;
+
+const BOM: &str = "\u{feff}";
+
+/// Returns `input` without a UTF-8 byte order mark, with `\n` line endings and with a single `\n`
+/// after the last non-empty line, or empty if there is none.
+///
+/// Only borrows `input` if it already has that form or just needs its BOM or trailing empty lines
+/// removed, so the solvers' own inputs never get copied.
+pub fn normalize(input: &str) -> Cow<'_, str> {
+    let input = input.strip_prefix(BOM).unwrap_or(input);
+    let end = input.trim_end_matches(['\r', '\n']).len();
+    if end == 0 {
+        return Cow::Borrowed("");
+    }
+
+    let content = &input[..end];
+    if input.as_bytes().get(end) == Some(&b'\n')
+        && memchr::memmem::find(content.as_bytes(), b"\r\n").is_none()
+    {
+        return Cow::Borrowed(&input[..=end]);
+    }
+
+    let mut normalized = content.replace("\r\n", "\n");
+    normalized.push('\n');
+    Cow::Owned(normalized)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn borrowed(input: &str) -> Option<&str> {
+        match normalize(input) {
+            Cow::Borrowed(normalized) => Some(normalized),
+            Cow::Owned(_) => None,
+        }
+    }
+
+    #[test]
+    fn test_canonical_is_borrowed() {
+        let input = "1 2\n3 4\n";
+        assert!(borrowed(input).is_some_and(|normalized| normalized.as_ptr() == input.as_ptr()));
+        assert_eq!(borrowed(input), Some(input));
+        assert_eq!(borrowed("\u{feff}1 2\n3 4\n"), Some(input));
+        assert_eq!(borrowed("1 2\n3 4\n\n\n"), Some(input));
+        assert_eq!(borrowed(""), Some(""));
+        assert_eq!(borrowed("\n\r\n"), Some(""));
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize("1 2\r\n3 4\r\n"), "1 2\n3 4\n");
+        assert_eq!(normalize("\u{feff}1 2\r\n3 4\r\n\r\n"), "1 2\n3 4\n");
+        assert_eq!(normalize("1 2\n3 4"), "1 2\n3 4\n");
+        assert_eq!(normalize("1 2\n3 4\r"), "1 2\n3 4\n");
+        // Only line endings change, other carriage returns are part of the input.
+        assert_eq!(normalize("1\r2\r\n"), "1\r2\n");
+    }
+
+    #[test]
+    fn test_solvers_accept_normalized_input() {
+        let input = format!("{}\r\n", "XMAS".repeat(35)).repeat(140);
+        assert_eq!(crate::y2024::day4::try_part1(&input), None);
+        assert_eq!(
+            crate::y2024::day4::try_part1(&normalize(&input)),
+            Some(crate::Answer::from(14490))
+        );
+
+        let input = "\u{feff}7 6 4 2 1\r\n1 3 6 7 9";
+        assert_eq!(
+            crate::y2024::day2::try_part1(&normalize(input)),
+            Some(crate::Answer::from(2))
+        );
+    }
+}
@@ -0,0 +1,250 @@
+//! ASCII number parsing shared by the days.
+//!
+//! Every routine returns the parsed value together with the number of bytes it consumed, so callers
+//! can step over the number and look at the separator that follows.
+
+#[cfg(feature = "nightly")]
+use core::simd::{num::SimdUint, Simd};
+
+const POW10: [u32; 10] = [
+    1,
+    10,
+    100,
+    1_000,
+    10_000,
+    100_000,
+    1_000_000,
+    10_000_000,
+    100_000_000,
+    1_000_000_000,
+];
+
+/// Converts an ASCII digit to its value. Other bytes wrap around to values above 9.
+#[inline(always)]
+pub const fn digit(byte: u8) -> u8 {
+    byte.wrapping_sub(b'0')
+}
+
+/// Parses the `N` ASCII digits at the start of `bytes`.
+///
+/// # Safety
+///
+/// `bytes` must be at least `N` bytes long. If those bytes are not all digits the result is
+/// meaningless, but still defined.
+#[inline(always)]
+pub unsafe fn fixed_unchecked<const N: usize>(bytes: &[u8]) -> (u32, usize) {
+    const { assert!(N >= 1 && N <= 9, "up to 9 digits fit in a u32") };
+
+    // Independent multiplications instead of `value * 10 + digit` keep the dependency chain short.
+    let mut value = 0;
+    let mut i = 0;
+    while i < N {
+        value += digit(unsafe { *bytes.get_unchecked(i) }) as u32 * POW10[N - 1 - i];
+        i += 1;
+    }
+    (value, N)
+}
+
+/// Parses the `N` ASCII digits at the start of `bytes`, or returns `None` if there are fewer.
+#[inline(always)]
+pub fn fixed<const N: usize>(bytes: &[u8]) -> Option<(u32, usize)> {
+    let digits = bytes.get(..N)?;
+    digits
+        .iter()
+        .all(u8::is_ascii_digit)
+        .then(|| unsafe { fixed_unchecked::<N>(digits) })
+}
+
+/// Parses one to `MAX` ASCII digits at the start of `bytes`, stopping at the first other byte.
+/// Returns `None` if `bytes` does not start with a digit.
+#[inline(always)]
+pub fn bounded<const MAX: usize>(bytes: &[u8]) -> Option<(u32, usize)> {
+    const { assert!(MAX >= 1 && MAX <= 9, "up to 9 digits fit in a u32") };
+
+    #[inline(always)]
+    fn scan(digits: &[u8]) -> (u32, usize) {
+        let mut value = 0;
+        let mut len = 0;
+        for &byte in digits {
+            let digit = digit(byte);
+            if digit > 9 {
+                break;
+            }
+            value = value * 10 + digit as u32;
+            len += 1;
+        }
+        (value, len)
+    }
+
+    // With at least `MAX` bytes left, the length of `digits` is known and the loop unrolls.
+    let (value, len) = match bytes.first_chunk::<MAX>() {
+        Some(digits) => scan(digits),
+        None => scan(bytes),
+    };
+    (len > 0).then_some((value, len))
+}
+
+/// Parses an optional `-` or `+` followed by one to `MAX` ASCII digits, like [`bounded`].
+#[inline(always)]
+pub fn signed<const MAX: usize>(bytes: &[u8]) -> Option<(i32, usize)> {
+    let (negative, sign_len) = match bytes.first() {
+        Some(b'-') => (true, 1),
+        Some(b'+') => (false, 1),
+        _ => (false, 0),
+    };
+    let (value, len) = bounded::<MAX>(&bytes[sign_len..])?;
+    let value = if negative {
+        -(value as i32)
+    } else {
+        value as i32
+    };
+    Some((value, sign_len + len))
+}
+
+/// [`bounded`] for `const fn`s: parses one to `MAX` ASCII digits starting at `bytes[start]`, or
+/// returns `None` if there is no digit there.
+pub const fn bounded_at<const MAX: usize>(bytes: &[u8], start: usize) -> Option<(u32, usize)> {
+    const { assert!(MAX >= 1 && MAX <= 9, "up to 9 digits fit in a u32") };
+
+    let mut value = 0;
+    let mut len = 0;
+    while len < MAX && start + len < bytes.len() {
+        let digit = digit(bytes[start + len]);
+        if digit > 9 {
+            break;
+        }
+        value = value * 10 + digit as u32;
+        len += 1;
+    }
+    if len > 0 {
+        Some((value, len))
+    } else {
+        None
+    }
+}
+
+/// Loads the 8 bytes at `start`, padding with zeros past the end of `bytes`.
+#[inline(always)]
+fn window(bytes: &[u8], start: usize) -> u64 {
+    match bytes.get(start..start + 8) {
+        Some(window) => u64::from_le_bytes(window.try_into().unwrap()),
+        None => {
+            let mut window = [0; 8];
+            window[..bytes.len() - start].copy_from_slice(&bytes[start..]);
+            u64::from_le_bytes(window)
+        }
+    }
+}
+
+/// Shifts the `N` digits at the start of a little-endian `window` to its top, leaving zeros (and
+/// therefore leading zeros) in the bytes below them.
+#[inline(always)]
+const fn align<const N: usize>(window: u64) -> u64 {
+    (window << (8 * (8 - N))) & 0x0F0F_0F0F_0F0F_0F0F
+}
+
+/// Parses `LANES` numbers of `N` ASCII digits each, starting `stride` bytes apart, in parallel.
+///
+/// Returns the numbers and the length up to the end of the last one. Panics if `bytes` is shorter
+/// than that. Non-digits give meaningless numbers, like in [`fixed_unchecked`].
+#[cfg(feature = "nightly")]
+#[inline(always)]
+pub fn many_fixed<const N: usize, const LANES: usize>(
+    bytes: &[u8],
+    stride: usize,
+) -> ([u32; LANES], usize) {
+    const { assert!(N >= 1 && N <= 8, "the digits must fit in a u64") };
+
+    let len = (LANES - 1) * stride + N;
+    assert!(bytes.len() >= len);
+
+    // Combines neighbouring digits, then pairs, then quadruples, as in `u64` SWAR parsing.
+    let digits = Simd::<u64, LANES>::from_array(core::array::from_fn(|lane| {
+        align::<N>(window(bytes, lane * stride))
+    }));
+    let pairs = (digits * Simd::splat(2561)) >> 8 & Simd::splat(0x00FF_00FF_00FF_00FF);
+    let quads = (pairs * Simd::splat(6_553_601)) >> 16 & Simd::splat(0x0000_FFFF_0000_FFFF);
+    let values = (quads * Simd::splat(42_949_672_960_001)) >> 32;
+    (values.cast::<u32>().to_array(), len)
+}
+
+/// Parses `LANES` numbers of `N` ASCII digits each, starting `stride` bytes apart.
+///
+/// Returns the numbers and the length up to the end of the last one. Panics if `bytes` is shorter
+/// than that. Non-digits give meaningless numbers, like in [`fixed_unchecked`].
+#[cfg(not(feature = "nightly"))]
+#[inline(always)]
+pub fn many_fixed<const N: usize, const LANES: usize>(
+    bytes: &[u8],
+    stride: usize,
+) -> ([u32; LANES], usize) {
+    const { assert!(N >= 1 && N <= 8, "the digits must fit in a u64") };
+
+    let len = (LANES - 1) * stride + N;
+    assert!(bytes.len() >= len);
+
+    let values = core::array::from_fn(|lane| {
+        let digits = align::<N>(window(bytes, lane * stride));
+        let pairs = (digits.wrapping_mul(2561) >> 8) & 0x00FF_00FF_00FF_00FF;
+        let quads = (pairs.wrapping_mul(6_553_601) >> 16) & 0x0000_FFFF_0000_FFFF;
+        (quads.wrapping_mul(42_949_672_960_001) >> 32) as u32
+    });
+    (values, len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fixed() {
+        assert_eq!(fixed::<5>(b"12345   67890"), Some((12345, 5)));
+        assert_eq!(fixed::<3>(b"007,"), Some((7, 3)));
+        assert_eq!(fixed::<5>(b"1234"), None);
+        assert_eq!(fixed::<5>(b"12a45"), None);
+        assert_eq!(
+            unsafe { fixed_unchecked::<9>(b"999999999") },
+            (999_999_999, 9)
+        );
+    }
+
+    #[test]
+    fn test_bounded() {
+        assert_eq!(bounded::<3>(b"123,4"), Some((123, 3)));
+        assert_eq!(bounded::<3>(b"12)"), Some((12, 2)));
+        assert_eq!(bounded::<3>(b"1"), Some((1, 1)));
+        assert_eq!(bounded::<3>(b"1234"), Some((123, 3)));
+        assert_eq!(bounded::<2>(b" 1"), None);
+        assert_eq!(bounded::<2>(b""), None);
+    }
+
+    #[test]
+    fn test_bounded_at() {
+        const VALUE: Option<(u32, usize)> = bounded_at::<3>(b"mul(12,4)", 4);
+        assert_eq!(VALUE, Some((12, 2)));
+        assert_eq!(bounded_at::<3>(b"1234", 0), bounded::<3>(b"1234"));
+        assert_eq!(bounded_at::<3>(b"12", 1), Some((2, 1)));
+        assert_eq!(bounded_at::<3>(b"12", 2), None);
+        assert_eq!(bounded_at::<3>(b"1,2", 1), None);
+    }
+
+    #[test]
+    fn test_signed() {
+        assert_eq!(signed::<3>(b"-42 "), Some((-42, 3)));
+        assert_eq!(signed::<3>(b"+7"), Some((7, 2)));
+        assert_eq!(signed::<3>(b"19"), Some((19, 2)));
+        assert_eq!(signed::<3>(b"-"), None);
+        assert_eq!(signed::<3>(b"--1"), None);
+    }
+
+    #[test]
+    fn test_many_fixed() {
+        let input = b"12345   67890\n00001   99999\n";
+        assert_eq!(many_fixed::<5, 2>(input, 8), ([12345, 67890], 13));
+        assert_eq!(many_fixed::<5, 2>(input, 14), ([12345, 1], 19));
+        assert_eq!(
+            many_fixed::<3, 4>(b"001,020,300,999", 4),
+            ([1, 20, 300, 999], 15)
+        );
+    }
+}
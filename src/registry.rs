@@ -0,0 +1,191 @@
+//! Every solved day and part, for tools that pick solutions at runtime.
+
+use crate::Answer;
+use std::{
+    any::Any,
+    fmt,
+    time::{Duration, Instant},
+};
+
+/// The answer of a solution and the time spent parsing and solving.
+#[derive(Debug, Clone, Copy)]
+pub struct Phases {
+    pub answer: Answer,
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+/// One named way of solving a day and part, such as `scalar` or `simd`.
+#[derive(Debug, Clone, Copy)]
+pub struct Implementation {
+    pub name: &'static str,
+    pub run: fn(&str) -> Answer,
+}
+
+/// The output of a day's `parse`, whatever its type, see [`Solution::parse`].
+pub type Parsed = Box<dyn Any>;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Solution {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    /// Every implementation of the fused fast path, the default one first.
+    pub implementations: &'static [Implementation],
+    /// Returns whether an input has the shape the implementations rely on.
+    pub is_valid: fn(&str) -> bool,
+    /// Runs the separate `parse` step of the day. It may panic on, or give meaningless answers
+    /// for, inputs that aren't [`Solution::is_valid`], but never reads out of bounds.
+    pub parse: fn(&str) -> Parsed,
+    /// Runs the separate `solve` step of the part on what [`Solution::parse`] returned.
+    ///
+    /// # Panics
+    ///
+    /// If `parsed` comes from another day.
+    pub solve: fn(&Parsed) -> Answer,
+}
+
+impl Solution {
+    /// Runs the default implementation.
+    pub fn run(&self, input: &str) -> Answer {
+        (self.implementations[0].run)(input)
+    }
+
+    pub fn implementation(&self, name: &str) -> Option<&'static Implementation> {
+        self.implementations.iter().find(|imp| imp.name == name)
+    }
+
+    /// Runs the separate `parse` and `solve` steps and times both.
+    pub fn phases(&self, input: &str) -> Phases {
+        let start = Instant::now();
+        let parsed = (self.parse)(input);
+        let parse = start.elapsed();
+
+        let start = Instant::now();
+        let answer = (self.solve)(&parsed);
+        let solve = start.elapsed();
+
+        Phases {
+            answer,
+            parse,
+            solve,
+        }
+    }
+}
+
+/// Formats as `2024 day 1 part 2`.
+impl fmt::Display for Solution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} day {} part {}", self.year, self.day, self.part)
+    }
+}
+
+/// Returns the output of `parse` inside `parsed`, for the `solve` of the same day.
+pub(crate) fn downcast<P: 'static>(parsed: &Parsed, _parse: impl Fn(&str) -> P) -> &P {
+    parsed
+        .downcast_ref()
+        .expect("parsed should come from the same day")
+}
+
+/// Lists the `SOLUTIONS` that `day!` generates in each `$year::$module`.
+macro_rules! solutions {
+    ($($year:ident::$module:ident,)+) => {
+        &[$(crate::$year::$module::SOLUTIONS[0], crate::$year::$module::SOLUTIONS[1]),+]
+    };
+}
+
+/// Every solved day and part, by year and then day. Each day declares its own entries with `day!`.
+pub static SOLUTIONS: &[Solution] = solutions! {
+    y2024::day1,
+    y2024::day2,
+    y2024::day3,
+    y2024::day4,
+};
+
+pub fn find(year: u16, day: u8, part: u8) -> Option<&'static Solution> {
+    SOLUTIONS
+        .iter()
+        .find(|s| s.year == year && s.day == day && s.part == part)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_phases_match_run() {
+        const INPUT: &str =
+            "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+        for part in [1, 2] {
+            let solution = find(2024, 3, part).unwrap();
+            assert_eq!(solution.phases(INPUT).answer, solution.run(INPUT));
+        }
+    }
+
+    /// An input with the exact shape the fast paths of `day` of `year` expect.
+    fn input(year: u16, day: u8) -> Option<String> {
+        let mut seed = 0x2024_u64;
+        let mut random = move |n: u64| {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (seed >> 33) % n
+        };
+        let input = match (year, day) {
+            (2024, 1) => (0..1000)
+                .map(|_| format!("{}   {}\n", 10000 + random(90000), 10000 + random(500)))
+                .collect(),
+            (2024, 2) => {
+                "7 6 4 2 1\n1 2 7 8 9\n9 7 6 2 1\n1 3 2 4 5\n8 6 4 4 1\n1 3 6 7 9\n".to_owned()
+            }
+            (2024, 3) => {
+                "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))\n"
+                    .to_owned()
+            }
+            (2024, 4) => (0..140)
+                .map(|_| {
+                    (0..140)
+                        .map(|_| b"XMAS"[random(4) as usize] as char)
+                        .chain(['\n'])
+                        .collect::<String>()
+                })
+                .collect(),
+            _ => return None,
+        };
+        Some(input)
+    }
+
+    #[test]
+    fn test_implementations_agree() {
+        for solution in SOLUTIONS {
+            let Some(input) = input(solution.year, solution.day) else {
+                continue;
+            };
+            let expected = solution.run(&input);
+            for implementation in solution.implementations {
+                assert_eq!(
+                    (implementation.run)(&input),
+                    expected,
+                    "{solution} {}",
+                    implementation.name
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_traces_match_run() {
+        for solution in SOLUTIONS {
+            let Some(input) = input(solution.year, solution.day) else {
+                continue;
+            };
+            let Some(steps) =
+                crate::trace::trace(solution.year, solution.day, solution.part, &input)
+            else {
+                continue;
+            };
+            let total = steps.last().map_or(Answer::from(0), |step| step.total);
+            assert_eq!(total, solution.run(&input), "{solution}");
+        }
+    }
+}
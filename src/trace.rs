@@ -0,0 +1,287 @@
+//! Narrates how the answers come about, one [`Step`] per pair, report, instruction or match.
+//!
+//! Only the days of 2024 have traces so far. They go through the scalar, bounds-checked views of
+//! each day ([`day2::reports`], [`day3::instructions`], [`day4::part1_matches`], ...) and never
+//! through `part1`/`part2`, so tracing costs nothing when it is not used.
+
+use crate::{
+    y2024::{day2, day3, day4},
+    Answer,
+};
+use std::fmt;
+
+/// A value of a [`Step`] field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    Int(i64),
+    Bool(bool),
+    Text(&'static str),
+    List(Vec<i64>),
+    None,
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Int(value) => value.fmt(f),
+            Value::Bool(value) => value.fmt(f),
+            Value::Text(value) => f.write_str(value),
+            Value::List(values) => write!(f, "{values:?}"),
+            Value::None => f.write_str("none"),
+        }
+    }
+}
+
+/// One thing the answer was built from, and the answer up to and including it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+    /// What the step looked at, such as `"pair"`, `"report"`, `"mul"` or `"match"`.
+    pub kind: &'static str,
+    pub fields: Vec<(&'static str, Value)>,
+    pub total: Answer,
+}
+
+/// Formats the step on one line, as `kind name=value ... total=N`.
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:<6}", self.kind)?;
+        for (name, value) in &self.fields {
+            write!(f, " {name}={value}")?;
+        }
+        write!(f, " total={}", self.total)
+    }
+}
+
+/// Serializes as a flat object: `{"kind": .., <fields>.., "total": ..}`.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Step {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+
+        let mut map = serializer.serialize_map(Some(self.fields.len() + 2))?;
+        map.serialize_entry("kind", self.kind)?;
+        for (name, value) in &self.fields {
+            match value {
+                Value::Int(value) => map.serialize_entry(name, value)?,
+                Value::Bool(value) => map.serialize_entry(name, value)?,
+                Value::Text(value) => map.serialize_entry(name, value)?,
+                Value::List(values) => map.serialize_entry(name, values.as_slice())?,
+                Value::None => map.serialize_entry(name, &())?,
+            }
+        }
+        map.serialize_entry("total", &self.total)?;
+        map.end()
+    }
+}
+
+type Steps<'a> = Box<dyn Iterator<Item = Step> + 'a>;
+
+/// Turns `(kind, fields, contribution)` into steps carrying the running total.
+fn accumulate<'a>(
+    steps: impl Iterator<Item = (&'static str, Vec<(&'static str, Value)>, Answer)> + 'a,
+) -> Steps<'a> {
+    Box::new(
+        steps.scan(Answer::from(0), |total, (kind, fields, contribution)| {
+            *total = *total + contribution;
+            Some(Step {
+                kind,
+                fields,
+                total: *total,
+            })
+        }),
+    )
+}
+
+/// Returns the steps behind the answer to `day` and `part` of `year`, or `None` if that day has no
+/// trace.
+/// The last step's total is the answer.
+///
+/// # Panics
+///
+/// If `input` isn't valid for `day` (see `dayN::is_valid`), on the first step that needs it.
+pub fn trace(year: u16, day: u8, part: u8, input: &str) -> Option<Steps<'_>> {
+    Some(match (year, day, part) {
+        (2024, 1, 1) => day1_part1(input),
+        (2024, 1, 2) => day1_part2(input),
+        (2024, 2, 1 | 2) => day2(input, part),
+        (2024, 3, 1 | 2) => day3(input, part),
+        (2024, 4, 1) => accumulate(day4::part1_matches(input).map(|m| {
+            let fields = vec![
+                ("row", Value::Int(m.row as i64)),
+                ("col", Value::Int(m.col as i64)),
+                ("direction", Value::Text(direction(m.direction))),
+            ];
+            ("match", fields, Answer::from(1))
+        })),
+        (2024, 4, 2) => accumulate(day4::part2_matches(input).map(|(row, col)| {
+            let fields = vec![
+                ("row", Value::Int(row as i64)),
+                ("col", Value::Int(col as i64)),
+            ];
+            ("match", fields, Answer::from(1))
+        })),
+        _ => return None,
+    })
+}
+
+/// Parses the two lists with bounds checks, unlike `day1::parse`, so any number of digits works.
+fn day1_lists(input: &str) -> (Vec<i64>, Vec<i64>) {
+    input
+        .lines()
+        .map(|line| {
+            let mut numbers = line
+                .split_whitespace()
+                .map(|number| number.parse::<i64>().expect("location should be a number"));
+            (numbers.next().unwrap(), numbers.next().unwrap())
+        })
+        .unzip()
+}
+
+fn day1_part1(input: &str) -> Steps<'_> {
+    let (mut left, mut right) = day1_lists(input);
+    left.sort_unstable();
+    right.sort_unstable();
+    accumulate(left.into_iter().zip(right).map(|(l, r)| {
+        let distance = l.abs_diff(r);
+        let fields = vec![
+            ("left", Value::Int(l)),
+            ("right", Value::Int(r)),
+            ("distance", Value::Int(distance as i64)),
+        ];
+        ("pair", fields, Answer::from(distance))
+    }))
+}
+
+fn day1_part2(input: &str) -> Steps<'_> {
+    let (left, right) = day1_lists(input);
+    accumulate(left.into_iter().map(move |l| {
+        let count = right.iter().filter(|r| **r == l).count() as i64;
+        let fields = vec![
+            ("left", Value::Int(l)),
+            ("count", Value::Int(count)),
+            ("similarity", Value::Int(l * count)),
+        ];
+        ("number", fields, Answer::from(l * count))
+    }))
+}
+
+fn day2(input: &str, part: u8) -> Steps<'_> {
+    accumulate(day2::reports(input).map(move |report| {
+        let mut fields = vec![
+            (
+                "levels",
+                Value::List(report.levels.iter().map(|level| *level as i64).collect()),
+            ),
+            ("safe", Value::Bool(report.is_safe)),
+        ];
+        let counted = if part == 1 {
+            report.is_safe
+        } else {
+            let removal = report.removal.map_or(Value::None, |i| Value::Int(i as i64));
+            fields.push(("removal", removal));
+            report.is_tolerated()
+        };
+        ("report", fields, Answer::from(counted as u8))
+    }))
+}
+
+fn day3(input: &str, part: u8) -> Steps<'_> {
+    let mut enabled = true;
+    accumulate(day3::instructions(input).map(move |(offset, instruction)| {
+        let mut fields = vec![("offset", Value::Int(offset as i64))];
+        let (kind, product) = match instruction {
+            day3::Instruction::Mul(l, r) => {
+                fields.push(("left", Value::Int(l as i64)));
+                fields.push(("right", Value::Int(r as i64)));
+                ("mul", l as u64 * r as u64)
+            }
+            day3::Instruction::Do => {
+                enabled = true;
+                ("do", 0)
+            }
+            day3::Instruction::Dont => {
+                enabled = false;
+                ("don't", 0)
+            }
+        };
+        // Part 1 ignores `do()` and `don't()`, so every `mul` counts.
+        let counted = part == 1 || enabled;
+        if part == 2 {
+            fields.push(("enabled", Value::Bool(enabled)));
+        }
+        let product = if counted { product } else { 0 };
+        fields.push(("product", Value::Int(product as i64)));
+        (kind, fields, Answer::from(product))
+    }))
+}
+
+fn direction(direction: day4::Direction) -> &'static str {
+    match direction {
+        day4::Direction::East => "east",
+        day4::Direction::West => "west",
+        day4::Direction::South => "south",
+        day4::Direction::North => "north",
+        day4::Direction::SouthEast => "south_east",
+        day4::Direction::SouthWest => "south_west",
+        day4::Direction::NorthEast => "north_east",
+        day4::Direction::NorthWest => "north_west",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn last(day: u8, part: u8, input: &str) -> Step {
+        trace(2024, day, part, input).unwrap().last().unwrap()
+    }
+
+    #[test]
+    fn test_totals_match_examples() {
+        let day1 = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+        assert_eq!(last(1, 1, day1).total, Answer::from(11));
+        assert_eq!(last(1, 2, day1).total, Answer::from(31));
+
+        let day2 = "7 6 4 2 1\n1 2 7 8 9\n9 7 6 2 1\n1 3 2 4 5\n8 6 4 4 1\n1 3 6 7 9\n";
+        assert_eq!(last(2, 1, day2).total, Answer::from(2));
+        assert_eq!(last(2, 2, day2).total, Answer::from(4));
+
+        let day3 = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))\n";
+        assert_eq!(last(3, 1, day3).total, Answer::from(161));
+        assert_eq!(last(3, 2, day3).total, Answer::from(48));
+
+        let day4 = "..X...\n.SAMX.\n.A..A.\nXMAS.S\n.X....\n";
+        assert_eq!(last(4, 1, day4).total, Answer::from(4));
+        assert!(trace(2024, 5, 1, "").is_none());
+    }
+
+    #[test]
+    fn test_steps() {
+        let day2 = "7 6 4 2 1\n1 3 2 4 5\n";
+        let steps: Vec<_> = trace(2024, 2, 2, day2).unwrap().collect();
+        assert_eq!(
+            steps[1].to_string(),
+            "report levels=[1, 3, 2, 4, 5] safe=false removal=1 total=2"
+        );
+        assert_eq!(
+            trace(2024, 3, 2, "don't()mul(2,3)\n")
+                .unwrap()
+                .map(|step| step.to_string())
+                .collect::<Vec<_>>(),
+            [
+                "don't  offset=0 enabled=false product=0 total=0",
+                "mul    offset=7 left=2 right=3 enabled=false product=0 total=0",
+            ]
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_json() {
+        let step = trace(2024, 4, 1, "XMAS\n").unwrap().next().unwrap();
+        assert_eq!(
+            serde_json::to_string(&step).unwrap(),
+            r#"{"kind":"match","row":0,"col":0,"direction":"east","total":1}"#
+        );
+    }
+}
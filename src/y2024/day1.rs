@@ -0,0 +1,457 @@
+use crate::{
+    parse::{bounded_at, fixed, fixed_unchecked},
+    Answer,
+};
+#[cfg(feature = "std")]
+use iterator_ilp::{IteratorILP, TrustedLowerBound};
+
+// https://rust.godbolt.org/z/coxTaWhYc
+#[cfg(target_arch = "x86_64")]
+#[inline(always)]
+pub fn atoi_see(input: &[u8]) -> u32 {
+    assert!(input.len() <= 5, "Input must be up to 5 characters");
+    let input_bytes = input;
+
+    // Constants matching the .LCPI1_0 and .LCPI1_1 in the original assembly.
+    static GODBOLT_0: [u8; 16] = [
+        208, 208, 208, 208, // -48 in 2's complement
+        0, 0, 0, 0, // Padding
+        0, 0, 0, 0, // Padding
+        0, 0, 0, 0, // Padding
+    ];
+
+    static GODBOLT_1: [u16; 8] = [10000, 0, 1000, 0, 100, 0, 10, 0];
+
+    let result: u32;
+    unsafe {
+        core::arch::asm!(
+            // Load 4 bytes from `input` into XMM0
+            "vmovd xmm0, dword ptr [rip + {input_ptr}]",
+
+            // Add adjustment bytes for ASCII-to-integer conversion
+            "vpaddb xmm0, xmm0, xmmword ptr [rip + {adjustment_table}]",
+
+            // Zero-extend the bytes to integers
+            "vpmovzxbd xmm0, xmm0",
+
+            // Multiply and accumulate digits
+            "vpmaddwd xmm0, xmm0, xmmword ptr [rip + {multipliers}]",
+
+            // Handle the final digit
+            "movzx eax, byte ptr [rip + {input_ptr} + 4]",
+            "add al, -48",
+            "movzx ecx, al",
+
+            // Sum the results
+            "vpshufd xmm1, xmm0, 238",
+            "vpaddd xmm0, xmm0, xmm1",
+            "vpsrlq xmm1, xmm0, 32",
+            "vpaddd xmm0, xmm0, xmm1",
+            "vmovd eax, xmm0",
+            "add eax, ecx",
+
+            // Output
+            out("eax") result,
+
+            // Inputs
+            input_ptr = in(reg) input_bytes.as_ptr(),
+            adjustment_table = sym GODBOLT_0,
+            multipliers = sym GODBOLT_1,
+
+            // Clobbers
+            out("ecx") _,
+            out("xmm0") _,
+            out("xmm1") _,
+        );
+    }
+    result
+}
+
+/// Portable version of the assembly above, which lowers to `simd128` on wasm.
+#[cfg(all(not(target_arch = "x86_64"), feature = "nightly"))]
+#[inline(always)]
+pub fn atoi_see(input: &[u8]) -> u32 {
+    use core::simd::prelude::*;
+
+    assert!(input.len() <= 5, "Input must be up to 5 characters");
+
+    const MULTIPLIERS: u32x8 = u32x8::from_array([10000, 1000, 100, 10, 1, 0, 0, 0]);
+    let digits = u8x8::load_or_default(input) - u8x8::splat(b'0');
+    (digits.cast::<u32>() * MULTIPLIERS).reduce_sum()
+}
+
+/// Sorts the numbers, which are below 2^18 like the 5-digit ones of the input, with two radix
+/// passes of 9 bits through a buffer on the stack, so it doesn't allocate.
+#[inline(always)]
+fn sort(values: &mut [i32; 1000]) {
+    let digit = |value: i32, pass: usize| (value as u32 >> (9 * pass)) as usize % 512;
+    let mut offsets = [[0u16; 512]; 2];
+    for value in values.iter() {
+        offsets[0][digit(*value, 0)] += 1;
+        offsets[1][digit(*value, 1)] += 1;
+    }
+    for offsets in &mut offsets {
+        let mut sum = 0;
+        for offset in offsets {
+            (*offset, sum) = (sum, sum + *offset);
+        }
+    }
+
+    let mut buffer = [0; 1000];
+    let scatter = |from: &[i32; 1000], to: &mut [i32; 1000], offsets: &mut [u16; 512], pass| {
+        for value in from {
+            let offset = &mut offsets[digit(*value, pass)];
+            to[*offset as usize] = *value;
+            *offset += 1;
+        }
+    };
+    let [low, high] = &mut offsets;
+    scatter(values, &mut buffer, low, 0);
+    scatter(&buffer, values, high, 1);
+}
+
+#[cfg(feature = "std")]
+#[inline(always)]
+fn sum(values: impl TrustedLowerBound<Item = u64>) -> u64 {
+    values.sum_ilp::<32, u64>()
+}
+
+// iterator_ilp enables std in num-traits, so fall back to the sequential sum.
+#[cfg(not(feature = "std"))]
+#[inline(always)]
+fn sum(values: impl Iterator<Item = u64>) -> u64 {
+    values.sum()
+}
+
+#[inline(always)]
+fn atoi(bytes: &[u8]) -> i32 {
+    assert_eq!(bytes.len(), 5);
+    unsafe { fixed_unchecked::<5>(bytes).0 as i32 }
+}
+
+#[cfg(any(target_arch = "x86_64", feature = "nightly"))]
+#[inline(always)]
+fn atoi_vectorized(bytes: &[u8]) -> i32 {
+    atoi_see(bytes) as i32
+}
+
+/// The two location lists, in input order.
+#[derive(Debug, Clone)]
+pub struct Parsed {
+    pub left: [i32; 1000],
+    pub right: [i32; 1000],
+}
+
+/// Parses the two lists. Numbers that aren't 5 digits give meaningless lists, see [`is_valid`].
+///
+/// # Panics
+///
+/// If `input` is shorter than 1000 lines of 14 bytes.
+#[inline(always)]
+pub fn parse(input: &str) -> Parsed {
+    parse_with(input, atoi)
+}
+
+#[inline(always)]
+fn parse_with(input: &str, atoi: impl Fn(&[u8]) -> i32) -> Parsed {
+    let input = input.as_bytes();
+    // Keeps the unchecked reads below in bounds, for the cost of one comparison.
+    assert!(input.len() >= 1000 * 14 - 1, "input should have 1000 lines");
+
+    unsafe {
+        let mut A: [i32; 1000] = [0; 1000];
+        let mut B: [i32; 1000] = [0; 1000];
+
+        for i in 0..1000 {
+            let line = i * 14;
+
+            let a_range = line..line + 5;
+            let b_range = line + 8..line + 13;
+            let a = atoi(input.get_unchecked(a_range));
+            let b = atoi(input.get_unchecked(b_range));
+
+            *A.get_unchecked_mut(i) = a;
+            *B.get_unchecked_mut(i) = b;
+        }
+
+        Parsed { left: A, right: B }
+    }
+}
+
+pub fn solve_part1(parsed: &Parsed) -> Answer {
+    let Parsed {
+        left: mut A,
+        right: mut B,
+    } = parsed.clone();
+
+    sort(&mut A);
+    sort(&mut B);
+
+    sum(A.iter().zip(B.iter()).map(|(l, r)| l.abs_diff(*r) as u64)).into()
+}
+
+pub fn solve_part2(parsed: &Parsed) -> Answer {
+    let mut freq: [u8; 100_000] = [0; 100_000];
+    for b in parsed.right {
+        freq[b as usize] += 1;
+    }
+
+    // Up to 1000 * 99999 * 255, more than an `i32` holds.
+    sum(parsed
+        .left
+        .iter()
+        .map(|a| *a as u64 * freq[*a as usize] as u64))
+    .into()
+}
+
+pub fn part1(input: &str) -> Answer {
+    part1_with(input, atoi)
+}
+
+/// [`part1`], parsing the numbers with [`atoi_see`].
+#[cfg(any(target_arch = "x86_64", feature = "nightly"))]
+pub fn part1_see(input: &str) -> Answer {
+    part1_with(input, atoi_vectorized)
+}
+
+#[inline(always)]
+fn part1_with(input: &str, atoi: impl Fn(&[u8]) -> i32) -> Answer {
+    let Parsed {
+        left: mut A,
+        right: mut B,
+    } = parse_with(input, atoi);
+
+    sort(&mut A);
+    sort(&mut B);
+
+    sum(A.iter().zip(B.iter()).map(|(l, r)| l.abs_diff(*r) as u64)).into()
+}
+
+/// # Panics
+///
+/// If `input` is shorter than 1000 lines of 14 bytes, or on some numbers that aren't 5 digits.
+pub fn part2(input: &str) -> Answer {
+    part2_with(input, atoi)
+}
+
+/// [`part2`], parsing the numbers with [`atoi_see`].
+#[cfg(any(target_arch = "x86_64", feature = "nightly"))]
+pub fn part2_see(input: &str) -> Answer {
+    part2_with(input, atoi_vectorized)
+}
+
+#[inline(always)]
+fn part2_with(input: &str, atoi: impl Fn(&[u8]) -> i32) -> Answer {
+    let input = input.as_bytes();
+    // Keeps the unchecked reads below in bounds, like in `parse`.
+    assert!(input.len() >= 1000 * 14 - 1, "input should have 1000 lines");
+
+    unsafe {
+        let mut A: [i32; 1000] = [0; 1000];
+        let mut freq: [u8; 100_000] = [0; 100_000];
+
+        for i in 0..1000 {
+            let line = i * 14;
+            let a_range = line..line + 5;
+            let b_range = line + 8..line + 13;
+
+            let a = atoi(input.get_unchecked(a_range));
+            *A.get_unchecked_mut(i) = a;
+
+            let b = atoi(input.get_unchecked(b_range));
+
+            freq[b as usize] += 1;
+        }
+
+        sum(A.iter().map(|a| *a as u64 * freq[*a as usize] as u64)).into()
+    }
+}
+
+/// Most lines the const solvers take, as many as a puzzle input has.
+const MAX_LINES: usize = 1000;
+
+/// Parses and sorts the two lists for the const solvers, with bounds checks and any number of
+/// digits, so unlike [`parse`] it also takes the puzzle's example. Returns them and their length.
+const fn sorted_lists_const(input: &str) -> ([u32; MAX_LINES], [u32; MAX_LINES], usize) {
+    let input = input.as_bytes();
+    let (mut left, mut right) = ([0; MAX_LINES], [0; MAX_LINES]);
+    let (mut i, mut len) = (0, 0);
+    while i < input.len() {
+        assert!(len < MAX_LINES, "at most 1000 lines fit");
+        let Some((l, digits)) = bounded_at::<9>(input, i) else {
+            panic!("expected a location ID");
+        };
+        i += digits;
+        while i < input.len() && input[i] == b' ' {
+            i += 1;
+        }
+        let Some((r, digits)) = bounded_at::<9>(input, i) else {
+            panic!("expected a second location ID");
+        };
+        i += digits + 1;
+
+        left[len] = l;
+        right[len] = r;
+        len += 1;
+    }
+
+    sort_const(&mut left, len);
+    sort_const(&mut right, len);
+    (left, right, len)
+}
+
+/// Sorts `values[..len]` with a heapsort, which const evaluation gets through much faster than the
+/// quadratic sorts that are easy to write without iterators.
+const fn sort_const(values: &mut [u32; MAX_LINES], len: usize) {
+    let mut start = len / 2;
+    while start > 0 {
+        start -= 1;
+        sift_down_const(values, start, len);
+    }
+    let mut end = len;
+    while end > 1 {
+        end -= 1;
+        values.swap(0, end);
+        sift_down_const(values, 0, end);
+    }
+}
+
+const fn sift_down_const(values: &mut [u32; MAX_LINES], mut root: usize, end: usize) {
+    loop {
+        let mut child = 2 * root + 1;
+        if child >= end {
+            return;
+        }
+        if child + 1 < end && values[child] < values[child + 1] {
+            child += 1;
+        }
+        if values[root] >= values[child] {
+            return;
+        }
+        values.swap(root, child);
+        root = child;
+    }
+}
+
+/// [`part1`] as a `const fn`, to solve an input embedded with `include_str!` at compile time.
+pub const fn part1_const(input: &str) -> Answer {
+    let (left, right, len) = sorted_lists_const(input);
+    let mut sum = 0u64;
+    let mut i = 0;
+    while i < len {
+        sum += left[i].abs_diff(right[i]) as u64;
+        i += 1;
+    }
+    Answer::from_u64(sum)
+}
+
+/// [`part2`] as a `const fn`, to solve an input embedded with `include_str!` at compile time.
+pub const fn part2_const(input: &str) -> Answer {
+    let (left, right, len) = sorted_lists_const(input);
+    let mut sum = 0u64;
+    // Both lists are sorted, so the occurrences in `right` of every number in `left` come in order.
+    let (mut i, mut j) = (0, 0);
+    while i < len {
+        while j < len && right[j] < left[i] {
+            j += 1;
+        }
+        let mut count = 0;
+        while j + count < len && right[j + count] == left[i] {
+            count += 1;
+        }
+        sum += left[i] as u64 * count as u64;
+        i += 1;
+    }
+    Answer::from_u64(sum)
+}
+
+/// Returns whether `input` has the shape [`part1`] and [`part2`] rely on: 1000
+/// lines of two 5-digit numbers separated by three spaces, and no right-hand
+/// number repeated more than 255 times.
+pub fn is_valid(input: &str) -> bool {
+    let input = input.as_bytes();
+    if !matches!(input.len(), 13_999 | 14_000) {
+        return false;
+    }
+
+    let mut B: [i32; 1000] = [0; 1000];
+    for (i, line) in input.chunks(14).enumerate() {
+        let (Some(_), Some((b, _))) = (fixed::<5>(line), fixed::<5>(&line[8..])) else {
+            return false;
+        };
+        if &line[5..8] != b"   " || !matches!(line.get(13), Some(b'\n') | None) {
+            return false;
+        }
+
+        B[i] = b as i32;
+    }
+
+    sort(&mut B);
+    !B.chunk_by(|l, r| l == r)
+        .any(|run| run.len() > u8::MAX as usize)
+}
+
+day! {
+    year: 2024,
+    day: 1,
+    part1: [
+        "scalar" => part1,
+        #[cfg(target_arch = "x86_64")] "asm" => part1_see,
+        #[cfg(all(not(target_arch = "x86_64"), feature = "nightly"))] "simd" => part1_see,
+    ],
+    part2: [
+        "scalar" => part2,
+        #[cfg(target_arch = "x86_64")] "asm" => part2_see,
+        #[cfg(all(not(target_arch = "x86_64"), feature = "nightly"))] "simd" => part2_see,
+    ],
+    const_solvers: [part1_const, part2_const],
+    // The answers of the synthetic input are checked against the reference solutions in
+    // `fuzz/src/lib.rs`.
+    examples: [include_str!("../../synthetic/2024/day1.txt") => (1192344, 679982)],
+    small_examples: [include_str!("../../examples/2024/day1.txt") => (11, 31)],
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SHORT: &str = "3   4\n4   3\n";
+    const EXAMPLE: &str = include_str!("../../examples/2024/day1.txt");
+
+    #[test]
+    #[should_panic]
+    fn test_parse_short_input() {
+        parse(SHORT);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_part2_short_input() {
+        part2(SHORT);
+    }
+
+    #[test]
+    fn test_score_beyond_i32() {
+        // Every left number matches the 255 copies of 99999 on the right.
+        let input: String = (0..1000)
+            .map(|i| format!("99999   {}\n", if i < 255 { 99999 } else { 10000 + i }))
+            .collect();
+        let score = Answer::from(25_499_745_000_u64);
+        assert_eq!(try_part2(&input), Some(score));
+        assert_eq!(solve_part2(&parse(&input)), score);
+        assert_eq!(part2_const(&input), score);
+    }
+
+    #[test]
+    fn test_const_matches_runtime() {
+        const ANSWERS: [Answer; 2] = [part1_const(EXAMPLE), part2_const(EXAMPLE)];
+        // `part1` and `part2` only take 1000 lines of 5-digit numbers, unlike the example.
+        assert_eq!(ANSWERS, [Answer::from(11), Answer::from(31)]);
+
+        let input: String = (0..1000)
+            .map(|i| format!("{}   {}\n", 10000 + i * 7919 % 90000, 10000 + i * 31 % 500))
+            .collect();
+        assert_eq!(part1_const(&input), part1(&input));
+        assert_eq!(part2_const(&input), part2(&input));
+    }
+}
@@ -0,0 +1,477 @@
+#[cfg(feature = "std")]
+use crate::parse::bounded;
+use crate::{
+    parse::{bounded_at, digit},
+    Answer,
+};
+use arrayvec::ArrayVec;
+#[cfg(feature = "nightly")]
+use core::simd::{cmp::SimdPartialOrd, simd_swizzle, u8x64, u8x8, Select, Simd};
+
+#[cfg(not(feature = "nightly"))]
+use self::{scalar_is_any_valid as is_any_safe, scalar_is_valid as is_safe};
+#[cfg(feature = "nightly")]
+use self::{simd_is_any_valid as is_any_safe, simd_is_valid as is_safe};
+
+#[cfg(feature = "nightly")]
+#[inline(always)]
+fn simd_is_any_valid(level: &[u8]) -> bool {
+    if simd_is_valid(level) {
+        return true;
+    }
+
+    let item_mask = (1 << (level.len() - 2)) - 1;
+    let curr = u8x64::load_or_default(level);
+
+    #[rustfmt::skip]
+    let curr = simd_swizzle!(
+        curr,
+        [
+            1, 2, 3, 4, 5, 6, 7, 8,
+            0, 2, 3, 4, 5, 6, 7, 8,
+            0, 1, 3, 4, 5, 6, 7, 8,
+            0, 1, 2, 4, 5, 6, 7, 8,
+            0, 1, 2, 3, 5, 6, 7, 8,
+            0, 1, 2, 3, 4, 6, 7, 8,
+            0, 1, 2, 3, 4, 5, 7, 8,
+            0, 1, 2, 3, 4, 5, 6, 8,
+        ]
+    );
+
+    simd_is_valid_multi(curr, item_mask)
+}
+
+#[cfg(feature = "nightly")]
+#[inline(always)]
+fn simd_is_valid_multi(curr: u8x64, item_mask: u64) -> bool {
+    let next = curr.rotate_elements_left::<1>();
+    let is_asc = next.simd_gt(curr).to_bitmask();
+    let is_desc = next.simd_lt(curr).to_bitmask();
+
+    let asc0 = (is_asc & item_mask) == item_mask;
+    let asc1 = (is_asc >> 8 & item_mask) == item_mask;
+    let asc2 = (is_asc >> 16 & item_mask) == item_mask;
+    let asc3 = (is_asc >> 24 & item_mask) == item_mask;
+    let asc4 = (is_asc >> 32 & item_mask) == item_mask;
+    let asc5 = (is_asc >> 40 & item_mask) == item_mask;
+    let asc6 = (is_asc >> 48 & item_mask) == item_mask;
+    let asc7 = (is_asc >> 56 & item_mask) == item_mask;
+
+    let desc0 = (is_desc & item_mask) == item_mask;
+    let desc1 = (is_desc >> 8 & item_mask) == item_mask;
+    let desc2 = (is_desc >> 16 & item_mask) == item_mask;
+    let desc3 = (is_desc >> 24 & item_mask) == item_mask;
+    let desc4 = (is_desc >> 32 & item_mask) == item_mask;
+    let desc5 = (is_desc >> 40 & item_mask) == item_mask;
+    let desc6 = (is_desc >> 48 & item_mask) == item_mask;
+    let desc7 = (is_desc >> 56 & item_mask) == item_mask;
+
+    if (!asc0 && !desc0)
+        && (!asc1 && !desc1)
+        && (!asc2 && !desc2)
+        && (!asc3 && !desc3)
+        && (!asc4 && !desc4)
+        && (!asc5 && !desc5)
+        && (!asc6 && !desc6)
+        && (!asc7 && !desc7)
+    {
+        return false;
+    }
+
+    let mask = curr.simd_gt(next);
+    let diff = mask.select(curr - next, next - curr);
+    let mask = diff.simd_ge(Simd::splat(1)) & diff.simd_le(Simd::splat(3));
+    let mask = mask.to_bitmask();
+
+    let diff0 = (mask & item_mask) == item_mask;
+    let diff1 = (mask >> 8 & item_mask) == item_mask;
+    let diff2 = (mask >> 16 & item_mask) == item_mask;
+    let diff3 = (mask >> 24 & item_mask) == item_mask;
+    let diff4 = (mask >> 32 & item_mask) == item_mask;
+    let diff5 = (mask >> 40 & item_mask) == item_mask;
+    let diff6 = (mask >> 48 & item_mask) == item_mask;
+    let diff7 = (mask >> 56 & item_mask) == item_mask;
+
+    ((asc0 || desc0) && diff0)
+        || ((asc1 || desc1) && diff1)
+        || ((asc2 || desc2) && diff2)
+        || ((asc3 || desc3) && diff3)
+        || ((asc4 || desc4) && diff4)
+        || ((asc5 || desc5) && diff5)
+        || ((asc6 || desc6) && diff6)
+        || ((asc7 || desc7) && diff7)
+}
+
+#[cfg(feature = "nightly")]
+#[inline(always)]
+fn simd_is_valid(level: &[u8]) -> bool {
+    let item_mask = (1 << (level.len() - 1)) - 1;
+    let curr = u8x8::load_or_default(level);
+    let next = simd_swizzle!(curr, [1, 2, 3, 4, 5, 6, 7, 7]);
+    let is_asc = next.simd_gt(curr).to_bitmask() & item_mask;
+    let is_desc = next.simd_lt(curr).to_bitmask() & item_mask;
+    if is_asc != item_mask && is_desc != item_mask {
+        return false;
+    }
+
+    let mask = curr.simd_gt(next);
+    let diff = mask.select(curr - next, next - curr);
+    let mask = diff.simd_ge(Simd::splat(1)) & diff.simd_le(Simd::splat(3));
+    let mask = mask.to_bitmask() & item_mask;
+    mask == item_mask
+}
+
+#[inline(always)]
+fn scalar_is_any_valid(level: &[u8]) -> bool {
+    scalar_is_valid(level) || (0..level.len()).any(|skip| scalar_is_valid(&without(level, skip)))
+}
+
+#[inline(always)]
+fn scalar_is_valid(level: &[u8]) -> bool {
+    let is_asc = level.windows(2).all(|pair| pair[0] < pair[1]);
+    let is_desc = level.windows(2).all(|pair| pair[0] > pair[1]);
+    let is_close = level
+        .windows(2)
+        .all(|pair| (1..=3).contains(&pair[0].abs_diff(pair[1])));
+    (is_asc || is_desc) && is_close
+}
+
+#[inline(always)]
+fn without(level: &[u8], skip: usize) -> ArrayVec<u8, 8> {
+    let mut without = ArrayVec::new_const();
+    for (i, value) in level.iter().enumerate() {
+        if i != skip {
+            without.push(*value);
+        }
+    }
+    without
+}
+
+/// Counts the reports `$is_safe` accepts. A macro rather than a function taking
+/// `is_safe`, since sharing the loop that way made the SIMD versions much slower.
+macro_rules! count_safe {
+    ($input:expr, $is_safe:path) => {{
+        let input = $input.as_bytes();
+
+        let mut parsed = ArrayVec::<u8, 8>::new_const();
+        let mut i = 0;
+        let mut count = 0;
+        loop {
+            if branches::unlikely(i >= input.len()) {
+                break;
+            }
+
+            let cur = unsafe { *input.get_unchecked(i) };
+            let next = *input.get(i + 1).unwrap_or(&b'\n');
+
+            match (cur.is_ascii_digit(), next.is_ascii_digit()) {
+                (true, false) => {
+                    parsed.push(digit(cur));
+                    if branches::likely(next == b' ') {
+                        i += 2;
+                    } else if branches::unlikely(next == b'\n') {
+                        count += $is_safe(&parsed) as u64;
+                        parsed = ArrayVec::new_const();
+                        i += 2;
+                    }
+                }
+                (true, true) => {
+                    parsed.push(digit(cur) * 10 + digit(next));
+
+                    let next_next = *input.get(i + 2).unwrap_or(&b'\n');
+                    if branches::likely(next_next == b' ') {
+                        i += 3;
+                    } else if branches::unlikely(next_next == b'\n') {
+                        count += $is_safe(&parsed) as u64;
+                        parsed = ArrayVec::new_const();
+                        i += 3;
+                    }
+                }
+                _ => panic!("expected a level at byte {i}"),
+            }
+        }
+
+        count
+    }};
+}
+
+target_feature_fn! {
+    pub fn part1(input: &str) -> u64 {
+        count_safe!(input, is_safe)
+    }
+}
+
+target_feature_fn! {
+    /// [`part1`] without SIMD.
+    pub fn part1_scalar(input: &str) -> u64 {
+        count_safe!(input, scalar_is_valid)
+    }
+}
+
+target_feature_fn! {
+    pub fn part2(input: &str) -> u64 {
+        count_safe!(input, is_any_safe)
+    }
+}
+
+target_feature_fn! {
+    /// [`part2`] without SIMD.
+    pub fn part2_scalar(input: &str) -> u64 {
+        count_safe!(input, scalar_is_any_valid)
+    }
+}
+
+/// Parses every report into its levels.
+///
+/// # Panics
+///
+/// If a report has more than 8 levels.
+#[cfg(feature = "std")]
+pub fn parse(input: &str) -> Vec<ArrayVec<u8, 8>> {
+    let mut reports = Vec::with_capacity(1000);
+    let mut levels = ArrayVec::new_const();
+    let mut rest = input.as_bytes();
+    while let Some((level, len)) = bounded::<2>(rest) {
+        levels.push(level as u8);
+        if rest.get(len) != Some(&b' ') {
+            reports.push(core::mem::take(&mut levels));
+        }
+        rest = rest.get(len + 1..).unwrap_or_default();
+    }
+
+    reports
+}
+
+#[cfg(feature = "std")]
+pub fn solve_part1(reports: &[ArrayVec<u8, 8>]) -> Answer {
+    reports
+        .iter()
+        .filter(|levels| is_safe(levels))
+        .count()
+        .into()
+}
+
+#[cfg(feature = "std")]
+pub fn solve_part2(reports: &[ArrayVec<u8, 8>]) -> Answer {
+    reports
+        .iter()
+        .filter(|levels| is_any_safe(levels))
+        .count()
+        .into()
+}
+
+/// Returns whether `input` only contains reports [`part1`] and [`part2`] can
+/// handle: one report per line, each with 2 to 8 levels of 1 or 2 digits
+/// separated by single spaces.
+pub fn is_valid(input: &str) -> bool {
+    let input = input.strip_suffix('\n').unwrap_or(input);
+    input.split('\n').all(|report| {
+        let mut levels = report.split(' ');
+        (2..=8).contains(&levels.clone().count())
+            && levels.all(|level| {
+                (1..=2).contains(&level.len()) && level.bytes().all(|b| b.is_ascii_digit())
+            })
+    })
+}
+
+/// A parsed report and the outcome of both safety checks, see [`reports`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub levels: ArrayVec<u8, 8>,
+    /// Whether the report is safe as-is, which is what [`part1`] counts.
+    pub is_safe: bool,
+    /// The first level whose removal makes an unsafe report safe.
+    pub removal: Option<usize>,
+}
+
+impl Report {
+    /// Whether the report is safe with at most one level removed, which is what
+    /// [`part2`] counts.
+    pub fn is_tolerated(&self) -> bool {
+        self.is_safe || self.removal.is_some()
+    }
+}
+
+/// Checks the reports one by one with scalar code. This is much slower than
+/// [`part1`] and [`part2`], but shows how they got their answers.
+///
+/// # Panics
+///
+/// If `input` isn't [`is_valid`].
+pub fn reports(input: &str) -> impl Iterator<Item = Report> + '_ {
+    input.lines().map(|report| {
+        let levels = report
+            .split(' ')
+            .map(|level| level.parse().expect("level should be a number"))
+            .collect::<ArrayVec<u8, 8>>();
+        let is_safe = scalar_is_valid(&levels);
+        let removal = if is_safe {
+            None
+        } else {
+            (0..levels.len()).find(|skip| scalar_is_valid(&without(&levels, *skip)))
+        };
+
+        Report {
+            levels,
+            is_safe,
+            removal,
+        }
+    })
+}
+
+/// Whether `levels[..len]` without the level at `skip` (none if out of range) is safe, like
+/// `scalar_is_valid`.
+const fn is_safe_const(levels: &[u8; 8], len: usize, skip: usize) -> bool {
+    let (mut ascending, mut descending) = (true, true);
+    let mut previous: Option<u8> = None;
+    let mut i = 0;
+    while i < len {
+        if i != skip {
+            if let Some(previous) = previous {
+                let level = levels[i];
+                ascending &= previous < level;
+                descending &= previous > level;
+                if !matches!(previous.abs_diff(level), 1..=3) {
+                    return false;
+                }
+            }
+            previous = Some(levels[i]);
+        }
+        i += 1;
+    }
+    ascending || descending
+}
+
+/// Counts the safe reports, or with `tolerate` the reports that are safe with at most one level
+/// removed, with bounds-checked parsing.
+const fn count_safe_const(input: &str, tolerate: bool) -> Answer {
+    let input = input.as_bytes();
+    let mut count = 0u64;
+    let mut i = 0;
+    while i < input.len() {
+        let mut levels = [0; 8];
+        let mut len = 0;
+        while i < input.len() && input[i] != b'\n' {
+            assert!(len < 8, "at most 8 levels fit");
+            let Some((level, digits)) = bounded_at::<2>(input, i) else {
+                panic!("expected a level");
+            };
+            levels[len] = level as u8;
+            len += 1;
+            i += digits;
+            if i < input.len() && input[i] == b' ' {
+                i += 1;
+            }
+        }
+        i += 1;
+
+        let mut safe = is_safe_const(&levels, len, usize::MAX);
+        let mut skip = 0;
+        while tolerate && !safe && skip < len {
+            safe = is_safe_const(&levels, len, skip);
+            skip += 1;
+        }
+        count += safe as u64;
+    }
+    Answer::from_u64(count)
+}
+
+/// [`part1`] as a `const fn`, to solve an input embedded with `include_str!` at compile time.
+pub const fn part1_const(input: &str) -> Answer {
+    count_safe_const(input, false)
+}
+
+/// [`part2`] as a `const fn`, to solve an input embedded with `include_str!` at compile time.
+pub const fn part2_const(input: &str) -> Answer {
+    count_safe_const(input, true)
+}
+
+day! {
+    year: 2024,
+    day: 2,
+    part1: [#[cfg(feature = "nightly")] "simd" => part1, "scalar" => part1_scalar],
+    part2: [#[cfg(feature = "nightly")] "simd" => part2, "scalar" => part2_scalar],
+    const_solvers: [part1_const, part2_const],
+    examples: [include_str!("../../examples/2024/day2.txt") => (2, 4)],
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const INPUT: &str = "7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9";
+
+    #[test]
+    fn test_const_matches_runtime() {
+        const EXAMPLE: &str = include_str!("../../examples/2024/day2.txt");
+        const ANSWERS: [Answer; 2] = [part1_const(EXAMPLE), part2_const(EXAMPLE)];
+        assert_eq!(ANSWERS, [part1(EXAMPLE), part2(EXAMPLE)]);
+        assert_eq!(ANSWERS, [Answer::from(2), Answer::from(4)]);
+    }
+
+    #[test]
+    fn test_is_valid() {
+        assert!(is_valid(INPUT));
+        assert!(is_valid("1 2\n"));
+        assert!(is_valid("1 12"));
+
+        assert!(!is_valid(""));
+        assert!(!is_valid("\n"));
+        assert!(!is_valid("1\n"));
+        assert!(!is_valid("1 2\n\n"));
+        assert!(!is_valid("1  2\n"));
+        assert!(!is_valid("1 2\r\n"));
+        assert!(!is_valid("1 123\n"));
+        assert!(!is_valid("1 2 3 4 5 6 7 8 9\n"));
+    }
+
+    #[test]
+    fn test_phases() {
+        let reports = parse(INPUT);
+        assert_eq!(reports.len(), 6);
+        assert_eq!(reports[5].as_slice(), [1, 3, 6, 7, 9]);
+        assert_eq!(solve_part1(&reports), Answer::from(2));
+        assert_eq!(solve_part2(&reports), Answer::from(4));
+    }
+
+    #[test]
+    fn test_reports() {
+        let reports = reports(INPUT).collect::<Vec<_>>();
+        assert_eq!(reports.len(), 6);
+        assert_eq!(reports.iter().filter(|report| report.is_safe).count(), 2);
+        assert_eq!(
+            reports
+                .iter()
+                .filter(|report| report.is_tolerated())
+                .count(),
+            4
+        );
+
+        assert_eq!(reports[3].levels.as_slice(), [1, 3, 2, 4, 5]);
+        assert_eq!(reports[3].removal, Some(1));
+        assert_eq!(reports[4].removal, Some(2));
+        assert_eq!(reports[1].removal, None);
+    }
+
+    #[test]
+    fn test_try_part1() {
+        assert_eq!(try_part1(INPUT), Some(Answer::from(2)));
+        assert_eq!(try_part1("1 a\n"), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_part1_too_many_levels() {
+        part1("1 2 3 4 5 6 7 8 9\n");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_parse_too_many_levels() {
+        parse("1 2 3 4 5 6 7 8 9\n");
+    }
+}
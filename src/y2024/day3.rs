@@ -0,0 +1,366 @@
+use crate::{
+    parse::{bounded, bounded_at},
+    Answer,
+};
+
+#[inline(always)]
+pub fn parse_mul(memory: &[u8], index: &mut usize) -> Option<(u16, u16)> {
+    unsafe {
+        if *memory.get_unchecked(*index) != b'm'
+            || *memory.get_unchecked(*index + 1) != b'u'
+            || *memory.get_unchecked(*index + 2) != b'l'
+            || *memory.get_unchecked(*index + 3) != b'('
+        {
+            *index += 1;
+            return None;
+        }
+        *index += 4;
+
+        let (first, cnt) = bounded::<3>(memory.get_unchecked(*index..))?;
+        *index += cnt;
+
+        if *memory.get_unchecked(*index) != b',' {
+            return None;
+        }
+        *index += 1;
+
+        let (second, cnt) = bounded::<3>(memory.get_unchecked(*index..))?;
+        *index += cnt;
+
+        if *memory.get_unchecked(*index) != b')' {
+            return None;
+        }
+        *index += 1;
+
+        Some((first as u16, second as u16))
+    }
+}
+
+#[cfg_attr(
+    target_arch = "x86_64",
+    target_feature(enable = "avx2,bmi1,bmi2,cmpxchg16b,lzcnt,movbe,popcnt")
+)]
+unsafe fn part1_inner(memory: &[u8]) -> Answer {
+    let mut index = 0;
+    // The sum grows with the input, so it accumulates through the checked ops of `Answer`.
+    let mut result = Answer::from(0u64);
+
+    let mul_finder = memchr::memmem::Finder::new("m");
+    while index < memory.len() {
+        let Some(next_mul) = mul_finder.find(memory.get_unchecked(index..)) else {
+            break;
+        };
+        index += next_mul;
+
+        if let Some((first, second)) = parse_mul(memory, &mut index) {
+            result = result + Answer::from(first) * Answer::from(second);
+        }
+    }
+
+    result
+}
+
+pub fn part1(input: &str) -> Answer {
+    unsafe { part1_inner(input.as_bytes()) }
+}
+
+target_feature_fn! {
+    pub fn part2(input: &str) -> Answer {
+        let memory = input.as_bytes();
+
+        let mut index = 0;
+        let mut result = Answer::from(0u64);
+
+        const DO_SIZE: usize = 4;
+        const DONT_SIZE: usize = 6;
+
+        let dont_finder = memchr::memmem::Finder::new("don't()");
+        let do_finder = memchr::memmem::Finder::new("do()");
+
+        while let Some(dont_offset) = dont_finder.find(memory.get_unchecked(index..)) {
+            let dont_idx = index + dont_offset;
+            // Include the `d` so a trailing partial `mul(` never reads past the slice.
+            result = result + unsafe { part1_inner(memory.get_unchecked(index..=dont_idx)) };
+
+            let do_offset = do_finder
+                .find(memory.get_unchecked(dont_idx + DONT_SIZE..))
+                .unwrap_or(memory.len() - dont_idx - DO_SIZE);
+            index = dont_idx + do_offset + DO_SIZE;
+        }
+
+        result + unsafe { part1_inner(memory.get_unchecked(index..)) }
+    }
+}
+
+/// Returns whether [`part1`] and [`part2`] can scan `input` without reading past
+/// its end, which requires it to not end in the middle of a `mul(X,Y)`.
+pub fn is_valid(input: &str) -> bool {
+    !matches!(
+        input.as_bytes().last(),
+        Some(b'm' | b'u' | b'l' | b'0'..=b'9')
+    )
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Mul(u16, u16),
+    Do,
+    Dont,
+}
+
+/// Returns the byte offset and kind of every instruction in `input`, found
+/// with bounds-checked parsing, so it works on any input.
+pub fn instructions(input: &str) -> impl Iterator<Item = (usize, Instruction)> + '_ {
+    let memory = input.as_bytes();
+    let mut index = 0;
+    core::iter::from_fn(move || {
+        while let Some(offset) = memchr::memchr2(b'm', b'd', &memory[index..]) {
+            let start = index + offset;
+            let rest = &memory[start..];
+            index = start + 1;
+
+            if rest.starts_with(b"do()") {
+                return Some((start, Instruction::Do));
+            }
+            if rest.starts_with(b"don't()") {
+                return Some((start, Instruction::Dont));
+            }
+
+            let Some(rest) = rest.strip_prefix(b"mul(") else {
+                continue;
+            };
+            let Some((first, cnt)) = bounded::<3>(rest) else {
+                continue;
+            };
+            let Some(rest) = rest[cnt..].strip_prefix(b",") else {
+                continue;
+            };
+            let Some((second, cnt)) = bounded::<3>(rest) else {
+                continue;
+            };
+            if rest[cnt..].starts_with(b")") {
+                return Some((start, Instruction::Mul(first as u16, second as u16)));
+            }
+        }
+        None
+    })
+}
+
+/// Collects the instructions of [`instructions`], without their offsets.
+#[cfg(feature = "std")]
+pub fn parse(input: &str) -> Vec<Instruction> {
+    instructions(input)
+        .map(|(_, instruction)| instruction)
+        .collect()
+}
+
+fn mul_sum(instructions: impl IntoIterator<Item = Instruction>) -> Answer {
+    instructions
+        .into_iter()
+        .map(|instruction| match instruction {
+            Instruction::Mul(l, r) => Answer::from(l) * Answer::from(r),
+            _ => Answer::from(0u64),
+        })
+        .sum()
+}
+
+fn enabled_mul_sum(instructions: impl IntoIterator<Item = Instruction>) -> Answer {
+    let mut enabled = true;
+    let mut result = Answer::from(0u64);
+    for instruction in instructions {
+        match instruction {
+            Instruction::Mul(l, r) if enabled => {
+                result = result + Answer::from(l) * Answer::from(r)
+            }
+            Instruction::Mul(..) => {}
+            Instruction::Do => enabled = true,
+            Instruction::Dont => enabled = false,
+        }
+    }
+    result
+}
+
+#[cfg(feature = "std")]
+pub fn solve_part1(instructions: &[Instruction]) -> Answer {
+    mul_sum(instructions.iter().copied())
+}
+
+#[cfg(feature = "std")]
+pub fn solve_part2(instructions: &[Instruction]) -> Answer {
+    enabled_mul_sum(instructions.iter().copied())
+}
+
+/// [`part1`] over [`instructions`], which works on any input.
+pub fn part1_scalar(input: &str) -> Answer {
+    mul_sum(instructions(input).map(|(_, instruction)| instruction))
+}
+
+/// [`part2`] over [`instructions`], which works on any input.
+pub fn part2_scalar(input: &str) -> Answer {
+    enabled_mul_sum(instructions(input).map(|(_, instruction)| instruction))
+}
+
+const fn starts_with_at(memory: &[u8], start: usize, prefix: &[u8]) -> bool {
+    if start + prefix.len() > memory.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < prefix.len() {
+        if memory[start + i] != prefix[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// The operands of a `mul(X,Y)` at `start`, as [`instructions`] finds them.
+const fn mul_at(memory: &[u8], start: usize) -> Option<(u32, u32)> {
+    if !starts_with_at(memory, start, b"mul(") {
+        return None;
+    }
+    let Some((l, digits)) = bounded_at::<3>(memory, start + 4) else {
+        return None;
+    };
+    let comma = start + 4 + digits;
+    if !starts_with_at(memory, comma, b",") {
+        return None;
+    }
+    let Some((r, digits)) = bounded_at::<3>(memory, comma + 1) else {
+        return None;
+    };
+    if !starts_with_at(memory, comma + 1 + digits, b")") {
+        return None;
+    }
+    Some((l, r))
+}
+
+/// Sums the products of the `mul`s, with `conditional` only the ones `do()` and `don't()` enable.
+const fn mul_sum_const(input: &str, conditional: bool) -> Answer {
+    let memory = input.as_bytes();
+    let mut enabled = true;
+    let mut sum = 0u64;
+    let mut i = 0;
+    while i < memory.len() {
+        if starts_with_at(memory, i, b"do()") {
+            enabled = true;
+        } else if starts_with_at(memory, i, b"don't()") {
+            enabled = false;
+        } else if let Some((l, r)) = mul_at(memory, i) {
+            if enabled || !conditional {
+                // Const fns can't use the checked ops of `Answer`, so check by hand.
+                let Some(next) = sum.checked_add(l as u64 * r as u64) else {
+                    return Answer::OVERFLOW;
+                };
+                sum = next;
+            }
+        }
+        i += 1;
+    }
+    Answer::from_u64(sum)
+}
+
+/// [`part1`] as a `const fn`, to solve an input embedded with `include_str!` at compile time.
+pub const fn part1_const(input: &str) -> Answer {
+    mul_sum_const(input, false)
+}
+
+/// [`part2`] as a `const fn`, to solve an input embedded with `include_str!` at compile time.
+pub const fn part2_const(input: &str) -> Answer {
+    mul_sum_const(input, true)
+}
+
+day! {
+    year: 2024,
+    day: 3,
+    part1: ["memmem" => part1, "scalar" => part1_scalar],
+    part2: ["memmem" => part2, "scalar" => part2_scalar],
+    const_solvers: [part1_const, part2_const],
+    examples: [
+        include_str!("../../examples/2024/day3.txt") => (161, 161),
+        include_str!("../../examples/2024/day3/part2.txt") => (161, 48),
+    ],
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1_simple() {
+        const INPUT: &str =
+            "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
+        assert_eq!(part1(INPUT), Answer::from(161));
+    }
+
+    #[test]
+    fn test_part2_simple() {
+        const INPUT: &str =
+            "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+        assert_eq!(part2(INPUT), Answer::from(48));
+    }
+
+    #[test]
+    fn test_part1_partial_prefix() {
+        assert_eq!(part1("mxl(2,4)mul(3,3)\n"), Answer::from(9));
+    }
+
+    #[test]
+    fn test_part2_trailing_dont() {
+        assert_eq!(part2("mul(2,4)don't()mul(3,3)\n"), Answer::from(8));
+    }
+
+    #[test]
+    fn test_beyond_u32() {
+        let input = "mul(999,999)".repeat(5000) + "\n";
+        assert_eq!(part1(&input), Answer::from(4_990_005_000u64));
+        assert_eq!(part2(&input), Answer::from(4_990_005_000u64));
+        assert_eq!(solve_part1(&parse(&input)), Answer::from(4_990_005_000u64));
+        assert_eq!(part1_scalar(&input), Answer::from(4_990_005_000u64));
+    }
+
+    #[test]
+    fn test_instructions() {
+        const INPUT: &str =
+            "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+        assert_eq!(
+            instructions(INPUT).collect::<Vec<_>>(),
+            [
+                (1, Instruction::Mul(2, 4)),
+                (20, Instruction::Dont),
+                (28, Instruction::Mul(5, 5)),
+                (48, Instruction::Mul(11, 8)),
+                (59, Instruction::Do),
+                (64, Instruction::Mul(8, 5)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_phases() {
+        const INPUT: &str =
+            "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+        let instructions = parse(INPUT);
+        assert_eq!(solve_part1(&instructions), Answer::from(161));
+        assert_eq!(solve_part2(&instructions), Answer::from(48));
+    }
+
+    #[test]
+    fn test_const_matches_runtime() {
+        const EXAMPLE: &str = include_str!("../../examples/2024/day3/part2.txt");
+        const ANSWERS: [Answer; 2] = [part1_const(EXAMPLE), part2_const(EXAMPLE)];
+        assert_eq!(ANSWERS, [part1(EXAMPLE), part2(EXAMPLE)]);
+        assert_eq!(ANSWERS, [Answer::from(161), Answer::from(48)]);
+        assert_eq!(part1_const("mul(1,2)mul(1234,1)mul(3,4"), Answer::from(2));
+    }
+
+    #[test]
+    fn test_is_valid() {
+        assert!(is_valid(""));
+        assert!(is_valid("mul(2,4)\n"));
+        assert!(is_valid("mul(2,4)"));
+
+        assert!(!is_valid("mul(2,4"));
+        assert!(!is_valid("xmu"));
+    }
+}
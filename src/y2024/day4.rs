@@ -0,0 +1,449 @@
+use crate::{
+    grid::{Grid, Row},
+    Answer,
+};
+
+const LINE_COUNT: usize = 140;
+const LINE_LEN: usize = 140 + 1 /* \n */;
+
+type Line = Row<3>;
+
+#[inline(always)]
+fn load_line(input: &[u8], i: usize) -> Line {
+    Row::new(&input[LINE_LEN * i..][..LINE_LEN - 1]) // exclude newline
+}
+
+fn count_hori(line: &Line) -> usize {
+    let x = line.eq(b'X');
+    let m = line.eq(b'M');
+    let a = line.eq(b'A');
+    let s = line.eq(b'S');
+
+    let is_xmas = (x << 3) & (m << 2) & (a << 1) & s;
+    let is_samx = (s << 3) & (a << 2) & (m << 1) & x;
+
+    is_xmas.count_ones() as usize + is_samx.count_ones() as usize
+}
+
+fn check_vert(line1: &Line, line2: &Line, line3: &Line, line4: &Line) -> usize {
+    let mut count = 0;
+
+    {
+        let l1x = line1.eq(b'X');
+        let l2m = line2.eq(b'M');
+        let l3a = line3.eq(b'A');
+        let l4s = line4.eq(b'S');
+        let is_xmas = l1x & l2m & l3a & l4s;
+        count += is_xmas.count_ones() as usize;
+    }
+
+    {
+        let l1s = line1.eq(b'S');
+        let l2a = line2.eq(b'A');
+        let l3m = line3.eq(b'M');
+        let l4x = line4.eq(b'X');
+        let is_samx = l1s & l2a & l3m & l4x;
+        count += is_samx.count_ones() as usize;
+    }
+
+    count
+}
+
+fn check_diag(line1: &Line, line2: &Line, line3: &Line, line4: &Line) -> usize {
+    let mut count = 0;
+
+    {
+        let l1x = line1.eq(b'X');
+        let l2m = line2.eq(b'M');
+        let l3a = line3.eq(b'A');
+        let l4s = line4.eq(b'S');
+        let left_xmas = l1x & (l2m >> 1) & (l3a >> 2) & (l4s >> 3);
+        let right_xmas = l1x & (l2m << 1) & (l3a << 2) & (l4s << 3);
+
+        count += left_xmas.count_ones() as usize + right_xmas.count_ones() as usize;
+    }
+    {
+        let l1s = line1.eq(b'S');
+        let l2a = line2.eq(b'A');
+        let l3m = line3.eq(b'M');
+        let l4x = line4.eq(b'X');
+        let left_samx = l1s & (l2a >> 1) & (l3m >> 2) & (l4x >> 3);
+        let right_samx = l1s & (l2a << 1) & (l3m << 2) & (l4x << 3);
+
+        count += left_samx.count_ones() as usize + right_samx.count_ones() as usize;
+    }
+
+    count
+}
+
+/// Counts `XMAS` in every direction, with `line(i)` loading the `i`th row.
+#[inline(always)]
+fn count_xmas(line: impl Fn(usize) -> Line) -> usize {
+    let mut count = 0;
+
+    for i in 0..(LINE_COUNT - 3) {
+        let line1 = line(i);
+        let line2 = line(i + 1);
+        let line3 = line(i + 2);
+        let line4 = line(i + 3);
+
+        count += count_hori(&line1);
+        count += check_vert(&line1, &line2, &line3, &line4);
+        count += check_diag(&line1, &line2, &line3, &line4);
+    }
+
+    count += count_hori(&line(LINE_COUNT - 3));
+    count += count_hori(&line(LINE_COUNT - 2));
+    count += count_hori(&line(LINE_COUNT - 1));
+
+    count
+}
+
+/// Counts `MAS` crosses, with `line(i)` loading the `i`th row.
+#[inline(always)]
+fn count_x_mas(line: impl Fn(usize) -> Line) -> usize {
+    let mut count = 0;
+
+    for i in 0..(LINE_COUNT - 2) {
+        let line1 = line(i);
+        let line2 = line(i + 1);
+        let line3 = line(i + 2);
+
+        let l1m = line1.eq(b'M');
+        let l2a = line2.eq(b'A');
+        let l3s = line3.eq(b'S');
+
+        let xmas_left = (l1m >> 1) & l2a & (l3s << 1);
+        let xmas_right = (l1m << 1) & l2a & (l3s >> 1);
+
+        let l1s = line1.eq(b'S');
+        let l3m = line3.eq(b'M');
+
+        let samx_left = (l1s >> 1) & l2a & (l3m << 1);
+        let samx_right = (l1s << 1) & l2a & (l3m >> 1);
+
+        count += (xmas_left & xmas_right).count_ones() as usize;
+        count += (samx_left & samx_right).count_ones() as usize;
+        count += (xmas_left & samx_right).count_ones() as usize;
+        count += (samx_left & xmas_right).count_ones() as usize;
+    }
+
+    count
+}
+
+target_feature_fn! {
+    pub fn part1(input: &str) -> usize {
+        let input = input.as_bytes();
+        // Keeps the unchecked row loads below in bounds, for the cost of one comparison.
+        assert!(input.len() >= LINE_LEN * LINE_COUNT - 1, "input should have 140 rows");
+        count_xmas(|i| load_line(input, i))
+    }
+}
+
+target_feature_fn! {
+    pub fn part2(input: &str) -> usize {
+        let input = input.as_bytes();
+        // Keeps the unchecked row loads below in bounds, for the cost of one comparison.
+        assert!(input.len() >= LINE_LEN * LINE_COUNT - 1, "input should have 140 rows");
+        count_x_mas(|i| load_line(input, i))
+    }
+}
+
+/// The rows of the grid, loaded into SIMD registers.
+pub struct Parsed {
+    lines: [Line; LINE_COUNT],
+}
+
+/// Loads every row, which [`part1`] and [`part2`] otherwise do on the fly.
+pub fn parse(input: &str) -> Parsed {
+    let input = input.as_bytes();
+    Parsed {
+        lines: core::array::from_fn(|i| load_line(input, i)),
+    }
+}
+
+target_feature_fn! {
+    pub fn solve_part1(parsed: &Parsed) -> usize {
+        count_xmas(|i| parsed.lines[i])
+    }
+}
+
+target_feature_fn! {
+    pub fn solve_part2(parsed: &Parsed) -> usize {
+        count_x_mas(|i| parsed.lines[i])
+    }
+}
+
+/// Returns whether `input` is the 140x140 grid [`part1`] and [`part2`] expect.
+pub fn is_valid(input: &str) -> bool {
+    Grid::<3>::new(input)
+        .is_some_and(|grid| grid.width() == LINE_LEN - 1 && grid.height() == LINE_COUNT)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    East,
+    West,
+    South,
+    North,
+    SouthEast,
+    SouthWest,
+    NorthEast,
+    NorthWest,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 8] = [
+        Direction::East,
+        Direction::West,
+        Direction::South,
+        Direction::North,
+        Direction::SouthEast,
+        Direction::SouthWest,
+        Direction::NorthEast,
+        Direction::NorthWest,
+    ];
+
+    /// Returns the `(row, col)` step in this direction.
+    pub const fn offset(self) -> (isize, isize) {
+        match self {
+            Direction::East => (0, 1),
+            Direction::West => (0, -1),
+            Direction::South => (1, 0),
+            Direction::North => (-1, 0),
+            Direction::SouthEast => (1, 1),
+            Direction::SouthWest => (1, -1),
+            Direction::NorthEast => (-1, 1),
+            Direction::NorthWest => (-1, -1),
+        }
+    }
+}
+
+/// An `XMAS` starting at the `X` in `row` and `col`, read towards `direction`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match {
+    pub row: usize,
+    pub col: usize,
+    pub direction: Direction,
+}
+
+const fn grid_get(input: &[u8], width: usize, row: isize, col: isize) -> Option<u8> {
+    if row < 0 || col < 0 || col as usize >= width {
+        return None;
+    }
+    let index = row as usize * (width + 1) + col as usize;
+    if index < input.len() {
+        Some(input[index])
+    } else {
+        None
+    }
+}
+
+const fn grid_width(input: &[u8]) -> usize {
+    let mut width = 0;
+    while width < input.len() && input[width] != b'\n' {
+        width += 1;
+    }
+    width
+}
+
+/// Returns every `XMAS` [`part1`] counts, found with a scalar search over any
+/// rectangular grid of newline-terminated rows.
+pub fn part1_matches(input: &str) -> impl Iterator<Item = Match> + '_ {
+    let input = input.as_bytes();
+    let width = grid_width(input);
+    let rows = input.len().div_ceil(width + 1);
+
+    (0..rows)
+        .flat_map(move |row| (0..width).map(move |col| (row, col)))
+        .flat_map(|(row, col)| {
+            Direction::ALL.map(|direction| Match {
+                row,
+                col,
+                direction,
+            })
+        })
+        .filter(move |m| {
+            let (dr, dc) = m.direction.offset();
+            b"XMAS".iter().enumerate().all(|(i, c)| {
+                let i = i as isize;
+                grid_get(
+                    input,
+                    width,
+                    m.row as isize + dr * i,
+                    m.col as isize + dc * i,
+                ) == Some(*c)
+            })
+        })
+}
+
+/// Returns the `(row, col)` of the `A` in the middle of every `X-MAS` [`part2`]
+/// counts.
+pub fn part2_matches(input: &str) -> impl Iterator<Item = (usize, usize)> + '_ {
+    let input = input.as_bytes();
+    let width = grid_width(input);
+    let rows = input.len().div_ceil(width + 1);
+
+    (0..rows)
+        .flat_map(move |row| (0..width).map(move |col| (row, col)))
+        .filter(move |(row, col)| {
+            let get = |dr: isize, dc: isize| {
+                grid_get(input, width, *row as isize + dr, *col as isize + dc)
+            };
+            let is_mas =
+                |a, b| matches!((a, b), (Some(b'M'), Some(b'S')) | (Some(b'S'), Some(b'M')));
+
+            get(0, 0) == Some(b'A')
+                && is_mas(get(-1, -1), get(1, 1))
+                && is_mas(get(-1, 1), get(1, -1))
+        })
+}
+
+/// [`part1`] as a scalar count of [`part1_matches`].
+pub fn part1_scalar(input: &str) -> Answer {
+    part1_matches(input).count().into()
+}
+
+/// [`part2`] as a scalar count of [`part2_matches`].
+pub fn part2_scalar(input: &str) -> Answer {
+    part2_matches(input).count().into()
+}
+
+/// [`part1`] as a `const fn`, to solve an input embedded with `include_str!` at compile time.
+/// Counts the same matches as [`part1_matches`].
+pub const fn part1_const(input: &str) -> Answer {
+    let input = input.as_bytes();
+    let width = grid_width(input);
+    let rows = input.len().div_ceil(width + 1) as isize;
+
+    let mut count = 0u64;
+    let mut row = 0;
+    while row < rows {
+        let mut col = 0;
+        while col < width as isize {
+            let mut direction = 0;
+            while direction < Direction::ALL.len() {
+                let (dr, dc) = Direction::ALL[direction].offset();
+                let mut i = 0;
+                while i < 4 {
+                    match grid_get(input, width, row + dr * i, col + dc * i) {
+                        Some(c) if c == b"XMAS"[i as usize] => i += 1,
+                        _ => break,
+                    }
+                }
+                count += (i == 4) as u64;
+                direction += 1;
+            }
+            col += 1;
+        }
+        row += 1;
+    }
+    Answer::from_u64(count)
+}
+
+/// [`part2`] as a `const fn`, to solve an input embedded with `include_str!` at compile time.
+/// Counts the same matches as [`part2_matches`].
+pub const fn part2_const(input: &str) -> Answer {
+    const fn is_mas(a: Option<u8>, b: Option<u8>) -> bool {
+        matches!((a, b), (Some(b'M'), Some(b'S')) | (Some(b'S'), Some(b'M')))
+    }
+
+    let input = input.as_bytes();
+    let width = grid_width(input);
+    let rows = input.len().div_ceil(width + 1) as isize;
+
+    let mut count = 0u64;
+    let mut row = 0;
+    while row < rows {
+        let mut col = 0;
+        while col < width as isize {
+            let (up, down) = (row - 1, row + 1);
+            let (left, right) = (col - 1, col + 1);
+            count += (matches!(grid_get(input, width, row, col), Some(b'A'))
+                && is_mas(
+                    grid_get(input, width, up, left),
+                    grid_get(input, width, down, right),
+                )
+                && is_mas(
+                    grid_get(input, width, up, right),
+                    grid_get(input, width, down, left),
+                )) as u64;
+            col += 1;
+        }
+        row += 1;
+    }
+    Answer::from_u64(count)
+}
+
+day! {
+    year: 2024,
+    day: 4,
+    part1: ["simd" => part1, "scalar" => part1_scalar],
+    part2: ["simd" => part2, "scalar" => part2_scalar],
+    const_solvers: [part1_const, part2_const],
+    // The answers of the synthetic input are checked against the reference solutions in
+    // `fuzz/src/lib.rs`.
+    examples: [include_str!("../../synthetic/2024/day4.txt") => (599, 64)],
+    small_examples: [include_str!("../../examples/2024/day4.txt") => (18, 9)],
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX
+";
+
+    #[test]
+    fn test_part1_matches() {
+        let matches = part1_matches(INPUT).collect::<Vec<_>>();
+        assert_eq!(matches.len(), 18);
+        assert!(matches.contains(&Match {
+            row: 0,
+            col: 5,
+            direction: Direction::East
+        }));
+        assert!(matches.contains(&Match {
+            row: 9,
+            col: 3,
+            direction: Direction::NorthWest
+        }));
+    }
+
+    #[test]
+    fn test_part2_matches() {
+        let matches = part2_matches(INPUT).collect::<Vec<_>>();
+        assert_eq!(matches.len(), 9);
+        assert_eq!(matches[0], (1, 2));
+    }
+
+    #[test]
+    fn test_const_matches_runtime() {
+        const EXAMPLE: &str = include_str!("../../examples/2024/day4.txt");
+        const ANSWERS: [Answer; 2] = [part1_const(EXAMPLE), part2_const(EXAMPLE)];
+        // The bit-parallel `part1` and `part2` only take 140x140 grids.
+        assert_eq!(ANSWERS, [part1_scalar(EXAMPLE), part2_scalar(EXAMPLE)]);
+        assert_eq!(ANSWERS, [Answer::from(18), Answer::from(9)]);
+
+        let input = format!("{}\n", "XMAS".repeat(35)).repeat(140);
+        assert_eq!(part1_const(&input), part1(&input));
+        assert_eq!(part2_const(&input), part2(&input));
+    }
+
+    #[test]
+    fn test_phases() {
+        let input = format!("{}\n", "XMAS".repeat(35)).repeat(140);
+        let parsed = parse(&input);
+        assert_eq!(solve_part1(&parsed), part1(&input));
+        assert_eq!(solve_part2(&parsed), part2(&input));
+    }
+}
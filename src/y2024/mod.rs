@@ -0,0 +1,7 @@
+//! The solutions of Advent of Code 2024.
+
+#[allow(unused, non_snake_case)]
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
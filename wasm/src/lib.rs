@@ -0,0 +1,82 @@
+//! `wasm-bindgen` exports of the solvers, so they can run in a browser.
+//!
+//! Every export [`normalize`]s the input and goes through the checked
+//! `try_part*` entry points, as pasted input can't be trusted to have the shape
+//! the unchecked solvers expect.
+
+use aoc_2024_public::{
+    normalize::normalize,
+    y2024::{day1, day2, day3, day4},
+    Answer,
+};
+use wasm_bindgen::prelude::*;
+
+fn answer(solve: fn(&str) -> Option<Answer>, input: &str, day: u32) -> Result<String, JsError> {
+    match solve(&normalize(input)) {
+        None => Err(JsError::new(&format!(
+            "input is not a valid day {day} input"
+        ))),
+        Some(answer) if answer.is_overflow() => {
+            Err(JsError::new("the answer does not fit in 64 bits"))
+        }
+        Some(answer) => Ok(answer.to_string()),
+    }
+}
+
+#[wasm_bindgen]
+pub fn day1_part1(input: &str) -> Result<String, JsError> {
+    answer(day1::try_part1, input, 1)
+}
+
+#[wasm_bindgen]
+pub fn day1_part2(input: &str) -> Result<String, JsError> {
+    answer(day1::try_part2, input, 1)
+}
+
+#[wasm_bindgen]
+pub fn day2_part1(input: &str) -> Result<String, JsError> {
+    answer(day2::try_part1, input, 2)
+}
+
+#[wasm_bindgen]
+pub fn day2_part2(input: &str) -> Result<String, JsError> {
+    answer(day2::try_part2, input, 2)
+}
+
+#[wasm_bindgen]
+pub fn day3_part1(input: &str) -> Result<String, JsError> {
+    answer(day3::try_part1, input, 3)
+}
+
+#[wasm_bindgen]
+pub fn day3_part2(input: &str) -> Result<String, JsError> {
+    answer(day3::try_part2, input, 3)
+}
+
+#[wasm_bindgen]
+pub fn day4_part1(input: &str) -> Result<String, JsError> {
+    answer(day4::try_part1, input, 4)
+}
+
+#[wasm_bindgen]
+pub fn day4_part2(input: &str) -> Result<String, JsError> {
+    answer(day4::try_part2, input, 4)
+}
+
+/// Dispatches to the export for `day` and `part`, for pages with a day picker.
+#[wasm_bindgen]
+pub fn solve(day: u32, part: u32, input: &str) -> Result<String, JsError> {
+    match (day, part) {
+        (1, 1) => day1_part1(input),
+        (1, 2) => day1_part2(input),
+        (2, 1) => day2_part1(input),
+        (2, 2) => day2_part2(input),
+        (3, 1) => day3_part1(input),
+        (3, 2) => day3_part2(input),
+        (4, 1) => day4_part1(input),
+        (4, 2) => day4_part2(input),
+        _ => Err(JsError::new(&format!(
+            "day {day} part {part} is not solved"
+        ))),
+    }
+}
@@ -0,0 +1,64 @@
+#![cfg(target_arch = "wasm32")]
+
+use aoc_2024_public::y2024::day1::atoi_see;
+use aoc_2024_public_wasm::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+fn test_day2() {
+    const INPUT: &str = "7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9";
+
+    assert_eq!(day2_part1(INPUT).unwrap(), "2");
+    assert_eq!(solve(2, 2, INPUT).unwrap(), "4");
+}
+
+#[wasm_bindgen_test]
+fn test_day3() {
+    assert_eq!(
+        day3_part1("xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))")
+            .unwrap(),
+        "161"
+    );
+    assert_eq!(
+        day3_part2("xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))")
+            .unwrap(),
+        "48"
+    );
+}
+
+#[wasm_bindgen_test]
+fn test_day4() {
+    let input = format!("{}\n", "XMAS".repeat(35)).repeat(140);
+
+    assert_eq!(day4_part1(&input).unwrap(), "14490");
+    assert_eq!(day4_part2(&input).unwrap(), "4830");
+}
+
+#[wasm_bindgen_test]
+fn test_pasted_input() {
+    let input = format!(
+        "\u{feff}{}",
+        format!("{}\r\n", "XMAS".repeat(35)).repeat(140)
+    );
+
+    assert_eq!(day4_part1(&input).unwrap(), "14490");
+    assert_eq!(solve(2, 1, "7 6 4 2 1\r\n1 3 6 7 9\r\n\r\n").unwrap(), "2");
+}
+
+#[wasm_bindgen_test]
+fn test_invalid_input() {
+    assert!(day1_part1("12345 67890").is_err());
+    assert!(day4_part2("XMAS").is_err());
+    assert!(solve(5, 1, "").is_err());
+}
+
+#[wasm_bindgen_test]
+fn test_atoi_see() {
+    assert_eq!(atoi_see(b"12345"), 12345);
+    assert_eq!(atoi_see(b"00042"), 42);
+}